@@ -1,15 +1,18 @@
-use std::str::FromStr;
-
-use advent_of_code::day2_cubes::{CubeSet, Game};
-
-advent_of_code::solution!(2);
-
-pub fn part_one(input: &str) -> Option<u32> {
-    let games = input
-        .lines()
-        .map(|line| Game::from_str(line).expect("failed to parse game"))
-        .collect::<Vec<_>>();
+use advent_of_code::{
+    day2_cubes::{CubeSet, Game},
+    parse,
+};
+
+advent_of_code::solution!(2, parse = parse_input);
+
+pub fn parse_input(input: &str) -> Vec<Game> {
+    parse::lines(input)
+        .map(|line| line.parse_into())
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|error| panic!("{error:#}"))
+}
 
+pub fn part_one(games: &[Game]) -> Option<u32> {
     let input = CubeSet {
         red: 12,
         green: 13,
@@ -25,12 +28,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(summed_possible_game_ids)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let games = input
-        .lines()
-        .map(|line| Game::from_str(line).expect("failed to parse game"))
-        .collect::<Vec<_>>();
-
+pub fn part_two(games: &[Game]) -> Option<u32> {
     Some(games.iter().map(|game| game.power_of_min_set()).sum())
 }
 
@@ -40,13 +38,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(2286));
     }
 }
@@ -1,18 +1,22 @@
-use std::str::FromStr;
+use std::fmt::Display;
 
 use advent_of_code::day3_schematic::Schematic;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, parse = parse_input, visualise = visualise);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let schematic = Schematic::from_str(input).unwrap();
+pub fn parse_input(input: &str) -> Schematic {
+    input.parse().unwrap_or_else(|error| panic!("{error:#}"))
+}
 
-    Some(schematic.sum_of_part_numbers())
+pub fn visualise(schematic: &Schematic) -> impl Display + '_ {
+    schematic.visualise()
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let schematic = Schematic::from_str(input).unwrap();
+pub fn part_one(schematic: &Schematic) -> Option<u32> {
+    Some(schematic.sum_of_part_numbers())
+}
 
+pub fn part_two(schematic: &Schematic) -> Option<u32> {
     Some(schematic.sum_of_gear_ratios())
 }
 
@@ -22,13 +26,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(467835));
     }
 }
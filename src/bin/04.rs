@@ -1,25 +1,21 @@
-use advent_of_code::day4_cards::Card;
+use advent_of_code::{day4_cards::Card, parse};
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, parse = parse_input);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let points = input
-        .lines()
-        .map(|line| {
-            line.parse::<Card>()
-                .expect("failed to parse card")
-                .point_value()
-        })
-        .sum();
+pub fn parse_input(input: &str) -> Vec<Card> {
+    parse::lines(input)
+        .map(|line| line.parse_into())
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|error| panic!("{error:#}"))
+}
 
-    Some(points)
+pub fn part_one(cards: &[Card]) -> Option<u32> {
+    Some(cards.iter().map(Card::point_value).sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut cards: Vec<Card> = input
-        .lines()
-        .map(|line| line.parse().expect("failed to parse card"))
-        .collect();
+pub fn part_two(cards: &[Card]) -> Option<u32> {
+    // copies are tracked on the cards themselves.
+    let mut cards = cards.to_vec();
 
     for pos in 0..cards.len() {
         let matches = cards[pos].matches() as usize;
@@ -39,13 +35,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(30));
     }
 }
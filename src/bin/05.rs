@@ -1,20 +1,20 @@
 use advent_of_code::day5_almanac::Input;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse_input);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut parsed = input.parse::<Input>().expect("failed to parse");
+pub fn parse_input(input: &str) -> Input {
+    input.parse().unwrap_or_else(|error| panic!("{error:#}"))
+}
 
-    let min_loc = parsed.min_location();
+pub fn part_one(input: &Input) -> Option<u64> {
+    let min_loc = input.min_location();
 
     Some(min_loc)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let parsed = input.parse::<Input>().expect("failed to parse");
-
+pub fn part_two(input: &Input) -> Option<u64> {
     // parsed.expand_input_pairs();
-    let min_loc = parsed.min_location_range();
+    let min_loc = input.min_location_range();
 
     Some(min_loc)
 }
@@ -25,13 +25,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(46));
     }
 }
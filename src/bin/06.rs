@@ -3,7 +3,7 @@ use advent_of_code::day6_boats::{parse_day6_input, parse_day6_input_pt2};
 advent_of_code::solution!(6);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let races = parse_day6_input(input);
+    let races = parse_day6_input(input).unwrap_or_else(|error| panic!("{error:#}"));
 
     let winning_product = races
         .iter()
@@ -13,7 +13,11 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    Some(parse_day6_input_pt2(input).number_of_winning_combos())
+    Some(
+        parse_day6_input_pt2(input)
+            .unwrap_or_else(|error| panic!("{error:#}"))
+            .number_of_winning_combos(),
+    )
 }
 
 #[cfg(test)]
@@ -1,9 +1,16 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, parse = parse_input);
 
-use advent_of_code::day7_cards::Hand;
+use advent_of_code::{day7_cards::Hand, parse};
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut hands: Vec<Hand> = input.lines().map(|line| line.parse().unwrap()).collect();
+pub fn parse_input(input: &str) -> Vec<Hand> {
+    parse::lines(input)
+        .map(|line| line.parse_into())
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|error| panic!("{error:#}"))
+}
+
+pub fn part_one(hands: &[Hand]) -> Option<u32> {
+    let mut hands = hands.to_vec();
     hands.sort();
 
     Some(
@@ -15,8 +22,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut hands: Vec<Hand> = input.lines().map(|line| line.parse().unwrap()).collect();
+pub fn part_two(hands: &[Hand]) -> Option<u32> {
+    let mut hands = hands.to_vec();
     hands.iter_mut().for_each(Hand::use_jokers);
     hands.sort();
 
@@ -35,13 +42,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(5905));
     }
 }
@@ -1,23 +1,31 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, parse = parse_input);
 
-use advent_of_code::day9_oasis::{extrapolate_sequence, parse_input_line};
+use advent_of_code::{
+    day9_oasis::{extrapolate_sequence, parse_input_line},
+    parse,
+};
 
-pub fn part_one(input: &str) -> Option<i64> {
+pub fn parse_input(input: &str) -> Vec<Vec<i64>> {
+    parse::lines(input)
+        .map(parse_input_line)
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|error| panic!("{error:#}"))
+}
+
+pub fn part_one(sequences: &[Vec<i64>]) -> Option<i64> {
     Some(
-        input
-            .lines()
-            .map(parse_input_line)
-            .map(|seq| extrapolate_sequence(&seq, false))
+        sequences
+            .iter()
+            .map(|seq| extrapolate_sequence(seq, false))
             .sum::<i64>(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
+pub fn part_two(sequences: &[Vec<i64>]) -> Option<i64> {
     Some(
-        input
-            .lines()
-            .map(parse_input_line)
-            .map(|seq| extrapolate_sequence(&seq, true))
+        sequences
+            .iter()
+            .map(|seq| extrapolate_sequence(seq, true))
             .sum::<i64>(),
     )
 }
@@ -28,13 +36,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(2));
     }
 }
@@ -1,17 +1,28 @@
+use std::fmt::Display;
+
 use advent_of_code::day10_pipes::PipeGrid;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(10, parse = parse_input, visualise = visualise);
+
+pub fn parse_input(input: &str) -> PipeGrid {
+    input.parse().unwrap_or_else(|error| panic!("{error:#}"))
+}
+
+pub fn visualise(grid: &PipeGrid) -> impl Display + '_ {
+    grid.visualise()
+}
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut grid: PipeGrid = input.parse().unwrap();
+pub fn part_one(grid: &PipeGrid) -> Option<u32> {
+    // distances are recorded on the grid while searching.
+    let mut grid = grid.clone();
 
     let max = grid.find_max_distance();
 
     Some(max)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut grid: PipeGrid = input.parse().unwrap();
+pub fn part_two(grid: &PipeGrid) -> Option<u32> {
+    let mut grid = grid.clone();
 
     Some(grid.points_inside_pipe())
 }
@@ -22,14 +33,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part("examples", DAY, 1));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file_part(
+            "examples", DAY, 1,
+        )));
         assert_eq!(result, Some(8));
     }
 
     #[test]
-    #[ignore]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        )));
         assert_eq!(result, Some(4));
     }
 }
@@ -0,0 +1,200 @@
+//! Module for finding where a deterministic sequence of states starts repeating.
+//!
+//! The sequence is `start, step(start), step(step(start)), ...`. Since every state determines the next one, the
+//! sequence repeats as soon as a state comes up again. All functions run forever if no state ever repeats, which can
+//! only happen if there are infinitely many states.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// The states from index `prefix` on repeat every `period` steps, the states before that never come up again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The smallest index with the same state as index `n`.
+    pub fn index_of(&self, n: u64) -> usize {
+        match usize::try_from(n) {
+            Ok(n) if n < self.prefix => n,
+            _ => self.prefix + ((n - self.prefix as u64) % self.period as u64) as usize,
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, which only keeps two states at a time.
+pub fn floyd<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // the distance between both is now a multiple of the period, so they meet at the start of the cycle.
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// Brent's algorithm, which only keeps two states at a time and usually needs fewer steps than [`floyd`].
+pub fn brent<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the tortoise waits at powers of two until the hare comes around.
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // with the hare `period` steps ahead, both meet at the start of the cycle.
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// Remembers every state, so `step` runs only once per state until the first repetition.
+pub fn find<S: Eq + Hash + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = start;
+
+    for index in 0.. {
+        if let Some(first) = seen.insert(state.clone(), index) {
+            return Cycle {
+                prefix: first,
+                period: index - first,
+            };
+        }
+        state = step(&state);
+    }
+
+    unreachable!("ran out of indices")
+}
+
+/// The state after `n` steps, skipping whole periods of the sequence.
+pub fn state_after<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S, n: u64) -> S {
+    let cycle = brent(start.clone(), &mut step);
+
+    let mut state = start;
+    for _ in 0..cycle.index_of(n) {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    /// Finds the cycle by checking every earlier state.
+    fn brute_force(start: usize, table: &[usize]) -> Cycle {
+        let mut states = vec![start];
+        loop {
+            let next = table[states[states.len() - 1]];
+            if let Some(prefix) = states.iter().position(|&s| s == next) {
+                return Cycle {
+                    prefix,
+                    period: states.len() - prefix,
+                };
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn finds_prefix_and_period() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let step = |&s: &u32| if s == 4 { 2 } else { s + 1 };
+        let expected = Cycle {
+            prefix: 2,
+            period: 3,
+        };
+
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(find(0, step), expected);
+        assert_eq!(
+            find(3, step),
+            Cycle {
+                prefix: 0,
+                period: 3
+            }
+        );
+        assert_eq!(
+            brent(7, |_| 7),
+            Cycle {
+                prefix: 0,
+                period: 1
+            }
+        );
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = Rng::new(48);
+
+        for _ in 0..300 {
+            let len = rng.range(1, 40) as usize;
+            let table: Vec<usize> = (0..len)
+                .map(|_| rng.range(0, len as u64 - 1) as usize)
+                .collect();
+            let start = rng.range(0, len as u64 - 1) as usize;
+            let step = |s: &usize| table[*s];
+
+            let expected = brute_force(start, &table);
+            assert_eq!(floyd(start, step), expected);
+            assert_eq!(brent(start, step), expected);
+            assert_eq!(find(start, step), expected);
+        }
+    }
+
+    #[test]
+    fn skips_whole_periods() {
+        let step = |&(a, b): &(u64, u64)| (b, (a + b) % 10);
+        let mut states = vec![(0, 1)];
+        for _ in 0..200 {
+            states.push(step(&states[states.len() - 1]));
+        }
+
+        for n in [0, 1, 59, 60, 61, 199] {
+            assert_eq!(state_after((0, 1), step, n), states[n as usize]);
+        }
+
+        // the last digits of the fibonacci numbers repeat every 60 steps.
+        assert_eq!(brent((0, 1), step).period, 60);
+        assert_eq!(state_after((0, 1), step, 999_999_999_960), (0, 1));
+    }
+}
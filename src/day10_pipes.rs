@@ -1,10 +1,18 @@
-use std::{cmp, collections::VecDeque, str::FromStr};
-
-use itertools::Itertools;
+use std::{cmp, collections::HashSet, str::FromStr};
+
+use crate::{
+    direction::Direction,
+    geometry::{self, Point},
+    graph::{Graph, GraphBuilder},
+    grid::{Grid, Position},
+    parse::{Line, ParseError},
+    render::{Glyph, Highlight, Render},
+    template::{runner, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_YELLOW},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PipeGrid {
-    locations: Vec<Vec<PipeLocation>>,
+    locations: Grid<PipeLocation>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,47 +33,58 @@ pub enum PipeKind {
     Start,
 }
 
-impl PipeLocation {
-    pub fn is_vertex(&self) -> bool {
-        self.distance.is_some()
-            && matches!(
-                self.kind,
-                PipeKind::NorthEast
-                    | PipeKind::NorthWest
-                    | PipeKind::SouthWest
-                    | PipeKind::SouthEast
-            )
+impl PipeKind {
+    /// Draws pipes with box-drawing characters.
+    pub fn symbol(self) -> char {
+        match self {
+            PipeKind::NorthSouth => '│',
+            PipeKind::EastWest => '─',
+            PipeKind::NorthEast => '└',
+            PipeKind::NorthWest => '┘',
+            PipeKind::SouthWest => '┐',
+            PipeKind::SouthEast => '┌',
+            PipeKind::Ground => '.',
+            PipeKind::Start => 'S',
+        }
+    }
+
+    /// The two directions a pipe connects, [`None`] for ground and for the start, whose shape is unknown.
+    pub fn directions(self) -> Option<[Direction; 2]> {
+        match self {
+            PipeKind::NorthSouth => Some([Direction::North, Direction::South]),
+            PipeKind::EastWest => Some([Direction::East, Direction::West]),
+            PipeKind::NorthEast => Some([Direction::North, Direction::East]),
+            PipeKind::NorthWest => Some([Direction::North, Direction::West]),
+            PipeKind::SouthWest => Some([Direction::South, Direction::West]),
+            PipeKind::SouthEast => Some([Direction::South, Direction::East]),
+            PipeKind::Ground | PipeKind::Start => None,
+        }
+    }
+
+    /// Returns `true` if the pipe is open towards `direction`. The start may be open in every direction.
+    fn connects(self, direction: Direction) -> bool {
+        match self {
+            PipeKind::Start => true,
+            kind => kind
+                .directions()
+                .is_some_and(|directions| directions.contains(&direction)),
+        }
     }
 }
 
 impl PipeGrid {
     pub fn find_max_distance(&mut self) -> u32 {
-        let mut bfs_nodes = VecDeque::new();
-        let start = self.start_location();
-        bfs_nodes.push_back(start);
-
-        self.locations[start.0][start.1].distance = Some(0);
+        let graph = self.pipe_graph();
+        let _span = runner::span("find_max_distance");
+        let start = graph.node(&self.start_location()).unwrap();
 
         let mut max_distance = 0;
 
-        while let Some(search_loc) = bfs_nodes.pop_front() {
-            let mut next_nodes = self.search_locations(search_loc);
-
-            let next_dist = self.locations[search_loc.0][search_loc.1].distance.unwrap() + 1;
-
-            // filter for nodes that connect back to current loc
-            next_nodes.retain(|&loc| {
-                self.search_locations(loc).contains(&search_loc)
-                    && self.locations[loc.0][loc.1].distance.is_none()
-            });
-
-            if !next_nodes.is_empty() {
-                max_distance = cmp::max(max_distance, next_dist);
-            }
-
-            for (col, row) in next_nodes {
-                self.locations[col][row].distance = Some(next_dist);
-                bfs_nodes.push_back((col, row));
+        for (node, distance) in graph.bfs(start).into_iter().enumerate() {
+            if let Some(distance) = distance {
+                let distance = distance as u32;
+                self.locations[*graph.name(node)].distance = Some(distance);
+                max_distance = cmp::max(max_distance, distance);
             }
         }
 
@@ -73,155 +92,128 @@ impl PipeGrid {
     }
 
     pub fn points_inside_pipe(&mut self) -> u32 {
-        self.find_max_distance();
-        let vertices = self.vertices();
-        let area = shoelace(vertices.iter().copied());
-
-        // pick's theorem:
-        // A = i + b/2 - 1
-        // we have A and number of boundary points, solve for interior points
-        // i = A - b/2 + 1
-
-        (area + 1 - (vertices.len() / 2)) as u32
+        let boundary = self.trace_loop();
+        let _span = runner::span("pick");
+        geometry::interior_points(&boundary) as u32
     }
 
-    pub fn print_grid(&self) {
-        for row in &self.locations {
-            for loc in row {
-                if let Some(dist) = loc.distance {
-                    print!("{dist}");
-                } else {
-                    print!(".");
+    /// Draws the pipes with the loop, the tiles inside it and the location farthest from the start highlighted.
+    pub fn visualise(&self) -> Render<'_, PipeLocation> {
+        let graph = self.pipe_graph();
+        let start = graph.node(&self.start_location()).unwrap();
+        let distances = graph.bfs(start);
+
+        let pipe_loop: HashSet<Position> = graph
+            .nodes()
+            .filter(|&node| distances[node].is_some())
+            .map(|node| *graph.name(node))
+            .collect();
+        let (farthest, max_distance) = graph
+            .nodes()
+            .filter_map(|node| Some((*graph.name(node), distances[node]?)))
+            .max_by_key(|&(_, distance)| distance)
+            .unwrap();
+
+        // a tile is inside if a ray from it to the left crosses the loop an odd number of times. Only loop pipes that
+        // connect north count as crossings, so a ray along a horizontal pipe crosses it once or not at all.
+        let connects_north = |(x, y): Position| {
+            let node = graph.node(&(x, y)).unwrap();
+            graph
+                .neighbours(node)
+                .any(|next| y > 0 && *graph.name(next) == (x, y - 1))
+        };
+
+        let mut inside = vec![];
+        for y in 0..self.locations.height() {
+            let mut crossings = 0;
+            for x in 0..self.locations.width() {
+                if pipe_loop.contains(&(x, y)) {
+                    crossings += usize::from(connects_north((x, y)));
+                } else if crossings % 2 == 1 {
+                    inside.push((x, y));
                 }
             }
-            println!();
         }
-    }
 
-    fn start_location(&self) -> (usize, usize) {
-        let n_rows = self.locations[0].len();
-        let flat_loc = self
-            .locations
-            .iter()
-            .flatten()
-            .enumerate()
-            .find_map(|(i, loc)| {
-                if loc.kind == PipeKind::Start {
-                    Some(i)
-                } else {
-                    None
-                }
-            })
-            .expect("couldn't find start location");
-
-        let col = flat_loc / n_rows;
-        let row = flat_loc % n_rows;
+        Render::new(&self.locations, |location| {
+            Glyph::colored(location.kind.symbol(), ANSI_DIM)
+        })
+        .highlight(Highlight::new("loop", ANSI_YELLOW, pipe_loop))
+        .highlight(
+            Highlight::new(
+                format!("inside the loop, {} tiles", inside.len()),
+                ANSI_GREEN,
+                inside,
+            )
+            .with_marker('I'),
+        )
+        .highlight(
+            Highlight::new(
+                format!("farthest from the start, {max_distance} steps"),
+                ANSI_RED,
+                [farthest],
+            )
+            .with_marker('*'),
+        )
+    }
 
-        (col, row)
+    fn start_location(&self) -> Position {
+        self.locations
+            .find(|loc| loc.kind == PipeKind::Start)
+            .expect("couldn't find start location")
     }
 
-    fn search_locations(&self, location: (usize, usize)) -> Vec<(usize, usize)> {
-        let (col, row) = location;
-        let offsets = match self.locations[col][row].kind {
-            PipeKind::Start => vec![(-1, 0), (0, 1), (0, -1), (1, 0)],
-            PipeKind::NorthSouth => vec![(-1, 0), (1, 0)],
-            PipeKind::EastWest => vec![(0, -1), (0, 1)],
-            PipeKind::NorthEast => vec![(-1, 0), (0, 1)],
-            PipeKind::NorthWest => vec![(-1, 0), (0, -1)],
-            PipeKind::SouthEast => vec![(1, 0), (0, 1)],
-            PipeKind::SouthWest => vec![(1, 0), (0, -1)],
-            PipeKind::Ground => vec![],
-        };
+    /// The pipes as a graph, with an edge between every two locations whose pipes connect to each other.
+    fn pipe_graph(&self) -> Graph<Position> {
+        let _span = runner::span("pipe_graph");
+        let mut builder = GraphBuilder::new();
+        builder.add_node(self.start_location());
+
+        for location in self.locations.positions() {
+            let kind = self.locations[location].kind;
 
-        offsets
-            .into_iter()
-            .filter_map(|(dy, dx)| {
-                let y = col as i16 + dy;
-                let x = row as i16 + dx;
+            for direction in Direction::ORTHOGONAL {
+                let Some(next) = self.locations.step(location, direction.offset()) else {
+                    continue;
+                };
 
-                if y >= 0
-                    && y < self.locations.len() as i16
-                    && x >= 0
-                    && x < self.locations[0].len() as i16
+                if kind.connects(direction)
+                    && self.locations[next].kind.connects(direction.opposite())
                 {
-                    Some((y as usize, x as usize))
-                } else {
-                    None
+                    builder.add_edge(location, next, 1);
                 }
-            })
-            .collect()
+            }
+        }
+
+        builder.build()
     }
 
-    fn vertices(&mut self) -> Vec<(usize, usize)> {
-        // trace pipe loop and yield vertices
-        let start = self.start_location();
+    /// Trace the pipe loop and return every location on it, in order
+    fn trace_loop(&self) -> Vec<Point> {
+        let graph = self.pipe_graph();
+        let _span = runner::span("trace_loop");
+        let start = graph.node(&self.start_location()).unwrap();
         let (mut last, mut current) = (None, start);
 
-        let mut vertices = vec![];
+        let mut boundary = vec![];
 
         loop {
-            if self.locations[current.0][current.1].is_vertex() {
-                vertices.push(current);
-            }
-            let next = self
-                .search_locations(current)
-                .into_iter()
-                .find(|loc| Some(*loc) != last && self.search_locations(*loc).contains(&current));
+            boundary.push(Point::from(*graph.name(current)));
+            let next = graph.neighbours(current).find(|&node| Some(node) != last);
             last = Some(current);
             current = next.expect("couldn't find next node");
             if current == start {
                 break;
             }
         }
-        println!("vertices {vertices:?}");
-
-        vertices
-    }
-
-    fn fill_in_start_pipe(&mut self) -> (usize, usize) {
-        let (row, col) = self.start_location();
 
-        (row, col)
+        boundary
     }
 }
 
-fn shoelace(coords: impl Iterator<Item = (usize, usize)> + ExactSizeIterator + Clone) -> usize {
-    let first_term = expand_shoelace(coords.clone(), false);
-    let second_term = expand_shoelace(coords.clone(), true);
-
-    if first_term >= second_term {
-        (first_term - second_term) / 2
-    } else {
-        (second_term - first_term) / 2
-    }
-}
-
-fn expand_shoelace(
-    coords: impl Iterator<Item = (usize, usize)> + ExactSizeIterator + Clone,
-    start_with_x: bool,
-) -> usize {
-    // (x1y2) + (x2y3) + (x3y4) + ...
-
-    // loop around to capture y1 term
-    let n_terms = coords.len() + 1;
-
-    coords
-        .cycle()
-        .take(n_terms)
-        .tuple_windows()
-        .map(
-            |((x1, y1), (x2, y2))| {
-                if start_with_x {
-                    x1 * y2
-                } else {
-                    y1 * x2
-                }
-            },
-        )
-        .sum()
-}
+impl TryFrom<char> for PipeKind {
+    type Error = char;
 
-impl From<char> for PipeKind {
     // | is a vertical pipe connecting north and south.
     // - is a horizontal pipe connecting east and west.
     // L is a 90-degree bend connecting north and east.
@@ -230,8 +222,8 @@ impl From<char> for PipeKind {
     // F is a 90-degree bend connecting south and east.
     // . is ground; there is no pipe in this tile.
     // S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
-    fn from(value: char) -> Self {
-        match value {
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             '|' => Self::NorthSouth,
             '-' => Self::EastWest,
             'L' => Self::NorthEast,
@@ -240,27 +232,36 @@ impl From<char> for PipeKind {
             'F' => Self::SouthEast,
             '.' => Self::Ground,
             'S' => Self::Start,
-            _ => panic!("invalid pipe character"),
-        }
-    }
-}
-
-fn parse_line(line: &str) -> Vec<PipeLocation> {
-    line.chars()
-        .map(|c| PipeLocation {
-            kind: c.into(),
-            distance: None,
+            _ => return Err(value),
         })
-        .collect_vec()
+    }
 }
 
 impl FromStr for PipeGrid {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            locations: s.lines().map(parse_line).collect_vec(),
-        })
+        let mut has_start = false;
+        let locations = Grid::try_parse(s, |c, part| {
+            let kind =
+                PipeKind::try_from(c).map_err(|_| part.error("a pipe, one of | - L J 7 F . S"))?;
+            if kind == PipeKind::Start {
+                if has_start {
+                    return Err(part.error("a single start S"));
+                }
+                has_start = true;
+            }
+            Ok(PipeLocation {
+                kind,
+                distance: None,
+            })
+        })?;
+
+        if !has_start {
+            return Err(Line::new(1, s.lines().next().unwrap_or_default()).error("a start S"));
+        }
+
+        Ok(Self { locations })
     }
 }
 
@@ -269,20 +270,32 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_shoelace() {
-        let input = vec![(1, 2), (3, 7), (5, 4)];
+    fn requires_a_single_start() {
+        let error = ".S.\n.S.".parse::<PipeGrid>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a single start S");
 
-        // 1/2 ((x1y2 + x2y3 + x3y1) - (y1x2 + y2x3 + y3x1))
-        // 1/2 ((1*7  + 3*4  + 5*2 ) - ( 2*3 +  7*5 +  4*1))
-        // 1/2 (( 7   +  12  +  10 ) - (  6  +  35  +   4 ))
-        // 1/2 (         29        ) - (        45        ))
-
-        let first_term = expand_shoelace(input.iter().copied(), true);
-        assert_eq!(first_term, 29);
-        let second_term = expand_shoelace(input.iter().copied(), false);
-        assert_eq!(second_term, 45);
+        let error = "...\n.|.".parse::<PipeGrid>().unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (1, "a start S"));
+    }
 
-        let shoelace = shoelace(input.iter().copied());
-        assert_eq!(shoelace, 8)
+    #[test]
+    fn visualises_loop_and_inside() {
+        let grid: PipeGrid = "..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n.|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n.........."
+            .parse()
+            .unwrap();
+
+        let drawn = grid.visualise().plain(true).to_string();
+        let (map, legend) = drawn.split_once("\n\n").unwrap();
+
+        assert_eq!(map.lines().nth(6), Some(".│II││II│."));
+        assert_eq!(
+            map.matches('I').count() as u32,
+            grid.clone().points_inside_pipe()
+        );
+        assert_eq!(
+            legend,
+            "I inside the loop, 4 tiles\n* farthest from the start, 22 steps\n"
+        );
     }
 }
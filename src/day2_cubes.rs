@@ -1,6 +1,6 @@
 use std::{cmp, str::FromStr};
 
-use anyhow::{anyhow, Context};
+use crate::parse::{Line, ParseError};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Game {
@@ -96,55 +96,54 @@ impl Round {
 }
 
 impl FromStr for CubeColor {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "red" => Ok(Self::Red),
             "green" => Ok(Self::Green),
             "blue" => Ok(Self::Blue),
-            _ => Err(anyhow!("invalid color")),
+            _ => Err(Line::new(1, s).error("a color: red, green or blue")),
         }
     }
 }
 
 impl FromStr for ColorDraw {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // "1 red"
-        let (qty, color) = s
-            .split_once(' ')
-            .context("couldn't split ColorDraw string")?;
+        let (qty, color) = Line::new(1, s).split_once(" ")?;
 
         Ok(Self {
-            quantity: qty.parse()?,
-            color: color.parse()?,
+            quantity: qty.parse("a number of cubes")?,
+            color: color.parse_into()?,
         })
     }
 }
 
 impl FromStr for Round {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // "3 blue, 4 red"
-        let colors: Result<_, _> = s.split(", ").map(ColorDraw::from_str).collect();
+        let colors: Result<_, _> = Line::new(1, s)
+            .split(", ")
+            .map(|draw| draw.parse_into())
+            .collect();
         Ok(Self { colors: colors? })
     }
 }
 
 impl FromStr for Game {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (game, rounds) = s
-            .split_once(": ")
-            .context("failed to split game from rounds")?;
+        let (game, rounds) = Line::new(1, s).split_once(": ")?;
 
-        let game_num = game.replace("Game ", "").parse::<u32>()?;
+        let game_num = game.strip_prefix("Game ")?.parse("a game number")?;
 
-        let rounds: Result<_, _> = rounds.split("; ").map(Round::from_str).collect();
+        let rounds: Result<_, _> = rounds.split("; ").map(|round| round.parse_into()).collect();
 
         Ok(Self {
             number: game_num,
@@ -1,12 +1,21 @@
 use std::{cmp, str::FromStr};
 
+use crate::{
+    grid::{Grid, Position},
+    parse::ParseError,
+    render::{Glyph, Highlight, Render},
+    template::{ANSI_CYAN, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_YELLOW},
+};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Schematic {
+    /// the schematic as parsed, for drawing it
+    chars: Grid<char>,
     numbers: Vec<Number>,
     /// 2D grid of bools, true if a symbol exists at that position
-    symbol_grid: Vec<Vec<bool>>,
+    symbol_grid: Grid<bool>,
     /// 2D grid of gear markers
-    gear_markers: Vec<Vec<GearMarker>>,
+    gear_markers: Grid<GearMarker>,
 }
 
 impl Schematic {
@@ -27,10 +36,43 @@ impl Schematic {
     pub fn sum_of_gear_ratios(&self) -> u32 {
         self.gear_markers
             .iter()
-            .flatten()
-            .filter_map(GearMarker::gear_ratio)
+            .filter_map(|(_, marker)| marker.gear_ratio())
             .sum()
     }
+
+    /// Draws the schematic with part numbers, other numbers and gears highlighted
+    pub fn visualise(&self) -> Render<'_, char> {
+        let (parts, others): (Vec<&Number>, Vec<&Number>) = self
+            .numbers
+            .iter()
+            .partition(|number| number.touches_symbol(&self.symbol_grid));
+        let gears: Vec<Position> = self
+            .gear_markers
+            .iter()
+            .filter(|(_, marker)| marker.gear_ratio().is_some())
+            .map(|(position, _)| position)
+            .collect();
+
+        Render::new(&self.chars, |&c| match c {
+            '.' => Glyph::colored(c, ANSI_DIM),
+            c if c.is_ascii_digit() => Glyph::new(c),
+            c => Glyph::colored(c, ANSI_CYAN),
+        })
+        .highlight(Highlight::new(
+            "part number",
+            ANSI_GREEN,
+            parts.iter().flat_map(|number| number.positions()),
+        ))
+        .highlight(
+            Highlight::new(
+                "not a part number",
+                ANSI_RED,
+                others.iter().flat_map(|number| number.positions()),
+            )
+            .with_marker('x'),
+        )
+        .highlight(Highlight::new("gear", ANSI_YELLOW, gears).with_marker('G'))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,36 +84,31 @@ pub struct Number {
 }
 
 impl Number {
-    pub fn touches_symbol(&self, symbols: &[Vec<bool>]) -> bool {
-        // create bounding box around number
-        let top = self.y.saturating_sub(1);
-        let left = self.x_start.saturating_sub(1);
-        let right = cmp::min(self.x_end + 1, symbols[0].len() - 1);
-        let bottom = cmp::min(self.y + 1, symbols.len() - 1);
+    pub fn touches_symbol(&self, symbols: &Grid<bool>) -> bool {
+        self.bounding_box(symbols.width(), symbols.height())
+            .any(|position| symbols[position])
+    }
 
-        for y in top..=bottom {
-            for x in left..=right {
-                if symbols[y][x] {
-                    return true;
-                }
-            }
+    fn mark_gears(&self, gears: &mut Grid<GearMarker>) {
+        for position in self.bounding_box(gears.width(), gears.height()) {
+            gears[position].visit_gear(self.value);
         }
+    }
 
-        false
+    /// Positions of the digits of the number
+    fn positions(&self) -> impl Iterator<Item = Position> {
+        let y = self.y;
+        (self.x_start..=self.x_end).map(move |x| (x, y))
     }
 
-    fn mark_gears(&self, gears: &mut [Vec<GearMarker>]) {
-        // create bounding box around number
+    /// Positions of the number and all cells around it, clamped to the grid
+    fn bounding_box(&self, width: usize, height: usize) -> impl Iterator<Item = Position> {
         let top = self.y.saturating_sub(1);
         let left = self.x_start.saturating_sub(1);
-        let right = cmp::min(self.x_end + 1, gears[0].len() - 1);
-        let bottom = cmp::min(self.y + 1, gears.len() - 1);
+        let right = cmp::min(self.x_end + 1, width - 1);
+        let bottom = cmp::min(self.y + 1, height - 1);
 
-        for y in top..=bottom {
-            for x in left..=right {
-                gears[y][x].visit_gear(self.value);
-            }
-        }
+        (top..=bottom).flat_map(move |y| (left..=right).map(move |x| (x, y)))
     }
 }
 
@@ -110,11 +147,9 @@ impl GearMarker {
     }
 }
 
-/// Parse a line, and get position of all numbers and symbols
-fn parse_line(line: &str, line_number: usize) -> (Vec<Number>, Vec<bool>, Vec<GearMarker>) {
+/// Parse a line, and get position of all numbers
+fn parse_line(line: &str, line_number: usize) -> Vec<Number> {
     let mut numbers = vec![];
-    let mut symbols = vec![false; line.len()];
-    let mut gears = vec![GearMarker::NotGear; line.len()];
 
     let mut current_number: Option<u32> = None;
 
@@ -137,14 +172,6 @@ fn parse_line(line: &str, line_number: usize) -> (Vec<Number>, Vec<bool>, Vec<Ge
                     x_end: pos - 1,
                 })
             }
-
-            if c != '.' {
-                symbols[pos] = true;
-
-                if c == '*' {
-                    gears[pos] = GearMarker::MaybeGear;
-                }
-            }
         }
     }
 
@@ -159,26 +186,31 @@ fn parse_line(line: &str, line_number: usize) -> (Vec<Number>, Vec<bool>, Vec<Ge
         })
     }
 
-    (numbers, symbols, gears)
+    numbers
 }
 
 impl FromStr for Schematic {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse(s, |c| c)?;
         let mut schematic = Schematic {
-            numbers: vec![],
-            symbol_grid: vec![],
-            gear_markers: vec![],
+            numbers: s
+                .lines()
+                .enumerate()
+                .flat_map(|(line_number, line)| parse_line(line, line_number))
+                .collect(),
+            symbol_grid: chars.map(|&c| !c.is_ascii_digit() && c != '.'),
+            gear_markers: chars.map(|&c| {
+                if c == '*' {
+                    GearMarker::MaybeGear
+                } else {
+                    GearMarker::NotGear
+                }
+            }),
+            chars,
         };
 
-        for (line_number, line) in s.lines().enumerate() {
-            let (numbers, symbols, gears) = parse_line(line, line_number);
-            schematic.numbers.extend(numbers.iter());
-            schematic.symbol_grid.push(symbols);
-            schematic.gear_markers.push(gears);
-        }
-
         // mark gear ratios in schematic
         for number in &schematic.numbers {
             number.mark_gears(&mut schematic.gear_markers);
@@ -187,3 +219,33 @@ impl FromStr for Schematic {
         Ok(schematic)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visualises_part_numbers_and_gears() {
+        let schematic: Schematic = "467..114..\n...*......\n..35..633.\n......#...\n617*......"
+            .parse()
+            .unwrap();
+
+        let drawn = schematic.visualise().plain(true).to_string();
+
+        assert_eq!(
+            drawn,
+            [
+                "467..xxx..",
+                "...G......",
+                "..35..633.",
+                "......#...",
+                "617*......",
+                "",
+                "x not a part number",
+                "G gear",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
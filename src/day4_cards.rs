@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use anyhow::Context;
+use crate::parse::{Line, ParseError};
 
 #[derive(Debug, Clone)]
 pub struct Card {
@@ -45,31 +45,23 @@ impl Card {
 }
 
 impl FromStr for Card {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut card = Self {
-            winning_numbers: vec![],
-            numbers_you_have: vec![],
-            copies: 1,
+        let numbers = |part: Line| -> Result<Vec<u32>, ParseError> {
+            part.tokens()
+                .map(|token| token.parse("an unsigned integer"))
+                .collect()
         };
-        // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-        let (_card, all_numbers) = s.split_once(':').context("splitting card from numbers")?;
-
-        let (numbers, winners) = all_numbers
-            .split_once('|')
-            .context("splitting numbers from winners")?;
-
-        for number in numbers.split_ascii_whitespace() {
-            card.winning_numbers
-                .push(number.parse::<u32>().context("failed to parse number")?);
-        }
 
-        for winner in winners.split_ascii_whitespace() {
-            card.numbers_you_have
-                .push(winner.parse::<u32>().context("failed to parse number")?);
-        }
+        // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        let (_card, all_numbers) = Line::new(1, s).split_once(":")?;
+        let (winning_numbers, numbers_you_have) = all_numbers.split_once("|")?;
 
-        Ok(card)
+        Ok(Self {
+            winning_numbers: numbers(winning_numbers)?,
+            numbers_you_have: numbers(numbers_you_have)?,
+            copies: 1,
+        })
     }
 }
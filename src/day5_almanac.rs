@@ -1,10 +1,12 @@
-use std::{iter, mem, str::FromStr};
+use std::str::FromStr;
 
 use itertools::Itertools;
 
-/// start, end, modified
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct LocationRange(u64, u64, bool);
+use crate::{
+    interval::{Interval, IntervalSet},
+    parse::{self, Block, Line, ParseError},
+    template::runner,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Mapping {
@@ -43,98 +45,52 @@ impl Mapping {
 
         true
     }
-}
-
-impl LocationRange {
-    pub fn new(start: u64, len: u64) -> Self {
-        Self(start, start + len - 1, false)
-    }
-
-    pub fn start(&self) -> u64 {
-        self.0
-    }
-
-    pub fn end(&self) -> u64 {
-        self.1
-    }
 
-    pub fn modified(&self) -> bool {
-        self.2
+    /// the source locations this mapping applies to
+    pub fn source(&self) -> Interval<u64> {
+        Interval::with_len(self.src_start, self.length)
     }
 
-    pub fn reset_modified_flag(&mut self) {
-        self.2 = false;
+    /// split locations into the ones this mapping applies to, already mapped, and the remaining ones
+    pub fn apply(&self, locations: &IntervalSet<u64>) -> (IntervalSet<u64>, IntervalSet<u64>) {
+        let (inside, outside) = locations.split(self.source());
+        (inside.shift(self.distance()), outside)
     }
+}
 
-    // apply a mapping to all locations in this range, resulting in one or more resulting ranges
-    pub fn apply_mapping(&self, mapping: &Mapping) -> impl Iterator<Item = LocationRange> {
-        // mapping can result in 1, 2, or 3 output ranges (not overlapping, partially overlapping, or contained within)
-        let mut out_range = *self;
-
-        let prefix = if mapping.src_start() > self.start() && mapping.src_start() <= self.end() {
-            out_range.0 = mapping.src_start();
-            Some(LocationRange(self.start(), mapping.src_start() - 1, false))
-        } else {
-            None
-        };
-
-        let suffix = if mapping.src_end() >= self.start() && mapping.src_end() < self.end() {
-            out_range.1 = mapping.src_end();
-            Some(LocationRange(mapping.src_end() + 1, self.end(), false))
-        } else {
-            None
-        };
-
-        // apply mapping to original
-        if out_range.0 >= mapping.src_start() && out_range.1 <= mapping.src_end() {
-            out_range.0 = (out_range.0 as i64 + mapping.distance()) as u64;
-            out_range.1 = (out_range.1 as i64 + mapping.distance()) as u64;
-            out_range.2 = true;
-        }
+/// apply a batch of mappings, locations that no mapping applies to keep their value
+fn apply_mapping_batch(locations: IntervalSet<u64>, batch: &[Mapping]) -> IntervalSet<u64> {
+    let _span = runner::span("apply_mapping_batch");
+    let mut mapped = IntervalSet::new();
+    let mut remaining = locations;
 
-        prefix
-            .into_iter()
-            .chain(iter::once(out_range))
-            .chain(suffix)
+    for mapping in batch {
+        let (inside, outside) = mapping.apply(&remaining);
+        mapped = mapped.union(&inside);
+        remaining = outside;
     }
 
-    /// apply a batch of mappings
-    pub fn apply_mapping_batch(&self, batch: &[Mapping]) -> impl Iterator<Item = LocationRange> {
-        let mut mapped = vec![*self];
-        for mapping in batch {
-            let input_range = mem::take(&mut mapped);
-            for range in input_range {
-                if range.modified() {
-                    // preserve ranges that are already mapped
-                    mapped.push(range);
-                } else {
-                    mapped.extend(range.apply_mapping(mapping));
-                }
-            }
-        }
-
-        for range in &mut mapped {
-            range.reset_modified_flag();
-        }
-
-        mapped.into_iter()
-    }
+    mapped.union(&remaining)
 }
 
 impl Input {
-    // part 1 solution; modifies locations in-place
-    pub fn min_location(&mut self) -> u64 {
-        for location in &mut self.seed_locations {
-            for map in &self.maps {
-                for mapping in map {
-                    if mapping.transform_location(location) {
-                        break;
+    // part 1 solution
+    pub fn min_location(&self) -> u64 {
+        self.seed_locations
+            .iter()
+            .map(|&seed| {
+                let mut location = seed;
+                for map in &self.maps {
+                    for mapping in map {
+                        if mapping.transform_location(&mut location) {
+                            break;
+                        }
                     }
                 }
-            }
-        }
-
-        self.seed_locations.iter().copied().min().unwrap()
+                location
+            })
+            .min()
+            .unwrap()
     }
 
     // brute force for part 2 solution (very slow)
@@ -151,89 +107,74 @@ impl Input {
 
     // better part 2 solution that works with ranges
     pub fn min_location_range(&self) -> u64 {
-        let mut ranges = self.input_ranges().collect_vec();
-
-        for mapping_batch in &self.maps {
-            let input_ranges = mem::take(&mut ranges);
-
-            for range in input_ranges {
-                ranges.extend(range.apply_mapping_batch(mapping_batch));
-            }
-        }
+        let _span = runner::span("min_location_range");
 
-        ranges.iter().min().expect("no minimum").0
+        self.maps
+            .iter()
+            .fold(self.input_ranges(), |locations, batch| {
+                apply_mapping_batch(locations, batch)
+            })
+            .min()
+            .expect("no minimum")
     }
 
     // convert inputs into ranges
-    fn input_ranges(&self) -> impl Iterator<Item = LocationRange> + '_ {
+    fn input_ranges(&self) -> IntervalSet<u64> {
         self.seed_locations
             .iter()
             .tuples()
-            .map(|(&start, &len)| LocationRange::new(start, len))
+            .map(|(&start, &len)| Interval::with_len(start, len))
+            .collect()
     }
 }
 
 impl FromStr for Mapping {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
+    /// 49 53 8
+    /// dest, src, length
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // 49 53 8
-        // dest, src, length
-        let [dest, src, length] = s
-            .split_ascii_whitespace()
-            .collect::<Vec<_>>()
+        let line = Line::new(1, s);
+        let [dest_start, src_start, length] = line
+            .unsigned()?
             .try_into()
-            .expect("failed to parse mapping");
+            .map_err(|_| line.error("a mapping \"<dest> <src> <length>\""))?;
 
         Ok(Self {
-            dest_start: dest.parse().unwrap(),
-            src_start: src.parse().unwrap(),
-            length: length.parse().unwrap(),
+            dest_start,
+            src_start,
+            length,
         })
     }
 }
 
 impl FromStr for Input {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // first line: seed locations
-        let (seed_line, remainder) = s.split_once('\n').unwrap();
-
-        let seeds = seed_line
-            .split_once(':')
-            .unwrap()
-            .1
-            .split_ascii_whitespace()
-            .map(|loc| loc.parse().unwrap())
-            .collect_vec();
-
-        let mut mappings_vec = vec![];
-        let mut map_vec = vec![];
-
-        for line in remainder.lines() {
-            // "seeds: 1 2 3 4"
-            if line.ends_with(':') {
-                if !map_vec.is_empty() {
-                    mappings_vec.push(mem::take(&mut map_vec));
-                }
-                continue;
-            }
-
-            if line.is_empty() {
-                continue;
-            }
-
-            map_vec.push(line.parse::<Mapping>().unwrap());
-        }
-
-        if !map_vec.is_empty() {
-            mappings_vec.push(mem::take(&mut map_vec));
-        }
+        let _span = runner::span("parse_almanac");
+        let mut paragraphs = parse::paragraphs(s);
+
+        // first line: "seeds: 1 2 3 4"
+        let seed_line = paragraphs
+            .next()
+            .map_or(Line::new(1, ""), |paragraph| paragraph[0]);
+        let seeds = seed_line.field("seeds")?.unsigned()?;
+
+        // then one block of mappings per map, like "seed-to-soil map:"
+        let maps = paragraphs
+            .map(|paragraph| {
+                Block::from_paragraph(paragraph)?
+                    .lines
+                    .into_iter()
+                    .map(|line| line.parse_into())
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
 
         Ok(Self {
             seed_locations: seeds,
-            maps: mappings_vec,
+            maps,
         })
     }
 }
@@ -242,10 +183,14 @@ impl FromStr for Input {
 mod tests {
     use super::*;
 
+    fn intervals(set: &IntervalSet<u64>) -> Vec<Interval<u64>> {
+        set.iter().copied().collect()
+    }
+
     #[test]
     fn mapping_split() {
         // range start 4, len 10 [4, 5, 6, 7, 8, 9, 10, 11, 12, 13]
-        let range = LocationRange::new(4, 10);
+        let range: IntervalSet<u64> = [Interval::with_len(4, 10)].into_iter().collect();
 
         // [1, 2, 3]
         let no_overlap = Mapping {
@@ -254,10 +199,9 @@ mod tests {
             length: 3,
         };
 
-        assert_eq!(
-            range.apply_mapping(&no_overlap).collect_vec(),
-            [LocationRange(4, 13, false)]
-        );
+        let (mapped, unmapped) = no_overlap.apply(&range);
+        assert!(mapped.is_empty());
+        assert_eq!(intervals(&unmapped), [Interval::closed(4, 13)]);
 
         // [2, 3, 4] -> [10, 11, 12]
         let overlaps_start = Mapping {
@@ -266,10 +210,9 @@ mod tests {
             length: 3,
         };
 
-        assert_eq!(
-            range.apply_mapping(&overlaps_start).collect_vec(),
-            [LocationRange(12, 12, true), LocationRange(5, 13, false)]
-        );
+        let (mapped, unmapped) = overlaps_start.apply(&range);
+        assert_eq!(intervals(&mapped), [Interval::closed(12, 12)]);
+        assert_eq!(intervals(&unmapped), [Interval::closed(5, 13)]);
 
         // [13, 14, 15] -> [10, 11, 12]
         let overlaps_end = Mapping {
@@ -277,10 +220,10 @@ mod tests {
             dest_start: 10,
             length: 3,
         };
-        assert_eq!(
-            range.apply_mapping(&overlaps_end).collect_vec(),
-            [LocationRange(4, 12, false), LocationRange(10, 10, true)]
-        );
+
+        let (mapped, unmapped) = overlaps_end.apply(&range);
+        assert_eq!(intervals(&mapped), [Interval::closed(10, 10)]);
+        assert_eq!(intervals(&unmapped), [Interval::closed(4, 12)]);
 
         // [6, 7, 8] -> [10, 11, 12]
         let full_overlap = Mapping {
@@ -288,13 +231,36 @@ mod tests {
             dest_start: 10,
             length: 3,
         };
+
+        let (mapped, unmapped) = full_overlap.apply(&range);
+        assert_eq!(intervals(&mapped), [Interval::closed(10, 12)]);
+        assert_eq!(
+            intervals(&unmapped),
+            [Interval::closed(4, 5), Interval::closed(9, 13)]
+        );
+    }
+
+    #[test]
+    fn mapping_batch_keeps_unmapped_locations() {
+        let locations: IntervalSet<u64> = [Interval::closed(0, 9)].into_iter().collect();
+        let batch = [
+            // [0, 1] -> [20, 21]
+            Mapping {
+                src_start: 0,
+                dest_start: 20,
+                length: 2,
+            },
+            // [20, 21] -> [0, 1], must not apply to locations mapped by the first mapping
+            Mapping {
+                src_start: 20,
+                dest_start: 0,
+                length: 2,
+            },
+        ];
+
         assert_eq!(
-            range.apply_mapping(&full_overlap).collect_vec(),
-            [
-                LocationRange(4, 5, false),
-                LocationRange(10, 12, true),
-                LocationRange(9, 13, false)
-            ]
+            intervals(&apply_mapping_batch(locations, &batch)),
+            [Interval::closed(2, 9), Interval::closed(20, 21)]
         );
     }
 }
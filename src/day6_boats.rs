@@ -1,3 +1,5 @@
+use crate::parse::{self, Line, ParseError};
+
 #[derive(Debug, Clone, Copy)]
 pub struct Race {
     time: u64,
@@ -34,35 +36,47 @@ impl Race {
     }
 }
 
-pub fn parse_day6_input(input: &str) -> Vec<Race> {
-    let (time, distance) = input.split_once('\n').expect("failed to parse input");
+/// the `Time:` and `Distance:` values of the input
+fn race_fields(input: &str) -> Result<(Line<'_>, Line<'_>), ParseError> {
+    let mut lines = parse::lines(input);
+    let mut field = |number, label: &str| match lines.next() {
+        Some(line) => line.field(label),
+        None => Err(Line::new(number, "").error(format!("a \"{label}:\" line"))),
+    };
 
-    let (_, times) = time.split_once(':').expect("failed to split time line");
-    let (_, distances) = distance
-        .split_once(':')
-        .expect("failed to split distance line");
+    Ok((field(1, "Time")?, field(2, "Distance")?))
+}
 
-    let parsed_times = times.split_ascii_whitespace().map(|t| t.parse().unwrap());
-    let parsed_distances = distances
-        .split_ascii_whitespace()
-        .map(|t| t.parse().unwrap());
+pub fn parse_day6_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = race_fields(input)?;
+    let (parsed_times, parsed_distances) = (times.unsigned()?, distances.unsigned()?);
 
-    parsed_times
+    if parsed_times.len() != parsed_distances.len() {
+        return Err(distances.error(format!("{} distances", parsed_times.len())));
+    }
+
+    Ok(parsed_times
+        .into_iter()
         .zip(parsed_distances)
         .map(|(time, distance)| Race { time, distance })
-        .collect()
+        .collect())
 }
 
-pub fn parse_day6_input_pt2(input: &str) -> Race {
-    let (time, distance) = input.split_once('\n').expect("failed to parse input");
-
-    let (_, time) = time.split_once(':').expect("failed to split time line");
-    let (_, distance) = distance
-        .split_once(':')
-        .expect("failed to split distance line");
+pub fn parse_day6_input_pt2(input: &str) -> Result<Race, ParseError> {
+    let (time, distance) = race_fields(input)?;
 
-    let time = time.replace([' ', '\n'], "").parse().unwrap();
-    let distance = distance.replace([' ', '\n'], "").parse().unwrap();
+    // the kerning is wrong, all numbers of a line form a single number.
+    let join = |field: Line| {
+        field
+            .tokens()
+            .map(|token| token.text())
+            .collect::<String>()
+            .parse()
+            .map_err(|_| field.error("digits that form a single unsigned integer"))
+    };
 
-    Race { time, distance }
+    Ok(Race {
+        time: join(time)?,
+        distance: join(distance)?,
+    })
 }
@@ -1,8 +1,9 @@
 use std::{collections::BTreeMap, str::FromStr};
 
-use anyhow::bail;
 use itertools::Itertools;
 
+use crate::parse::{Line, ParseError};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Card {
     Joker,
@@ -117,7 +118,7 @@ impl Ord for Hand {
 }
 
 impl TryFrom<char> for Card {
-    type Error = anyhow::Error;
+    type Error = char;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         Ok(match value {
@@ -134,27 +135,31 @@ impl TryFrom<char> for Card {
             'Q' => Self::Queen,
             'K' => Self::King,
             'A' => Self::Ace,
-            _ => bail!("inavlid card"),
+            _ => return Err(value),
         })
     }
 }
 
 impl FromStr for Hand {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (cards, bid) = s.split_once(' ').expect("failed to split hand");
+        let (cards, bid) = Line::new(1, s).split_once(" ")?;
+
+        if cards.text().chars().count() != 5 {
+            return Err(cards.error("a hand of 5 cards"));
+        }
 
         let cards = cards
             .chars()
-            .map(|c| Card::try_from(c).expect("invalid card"))
-            .collect();
-
-        Ok({
-            Self {
-                cards,
-                bid: bid.parse().expect("invalid bid"),
-            }
+            .map(|(c, part)| {
+                Card::try_from(c).map_err(|_| part.error("a card, one of 23456789TJQKA"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            cards,
+            bid: bid.parse("a bid")?,
         })
     }
 }
@@ -162,6 +167,7 @@ impl FromStr for Hand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
 
     #[test]
     fn hand_identification() {
@@ -217,6 +223,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn locates_invalid_cards() {
+        let hands = "32T3K 765\nT55X5 684";
+        let error = parse::lines(hands)
+            .map(|line| line.parse_into::<Hand>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert!(format!("{error:#}").ends_with("2 | T55X5 684\n  |    ^"));
+
+        for hand in ["AAAAAA 5", " 765", "32T 765"] {
+            let error = hand.parse::<Hand>().unwrap_err();
+            assert_eq!(
+                (error.column, error.expected.as_str()),
+                (1, "a hand of 5 cards")
+            );
+        }
+    }
+
     #[test]
     fn hand_ordering() {
         assert!(
@@ -1,11 +1,13 @@
-use std::{collections::HashMap, str::FromStr};
+use std::str::FromStr;
 
-#[derive(Debug, Clone)]
-pub struct Node {
-    pub last_char: char,
-    pub left: usize,
-    pub right: usize,
-}
+use itertools::Itertools;
+
+use crate::{
+    cycle::{self, Cycle},
+    graph::{Graph, GraphBuilder, NodeId},
+    math,
+    parse::{self, Line, ParseError},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
@@ -16,143 +18,265 @@ pub enum Direction {
 #[derive(Debug, Clone)]
 pub struct Input {
     directions: Vec<Direction>,
-    nodes: Vec<Node>,
-    pt1_start: usize,
-    pt1_end: usize,
-    pt2_starts: Vec<usize>,
+    /// every node has an edge to its left node followed by an edge to its right node.
+    graph: Graph<String>,
+    pt1_start: NodeId,
+    pt1_end: NodeId,
+    pt2_starts: Vec<NodeId>,
 }
 
+/// A position on the map: the current node and the index of the next direction to follow.
+type State = (NodeId, usize);
+
 impl Input {
-    pub fn path_steps_pt1(&self) -> u32 {
-        let mut loc = self.pt1_start;
+    /// Returns [`None`] if the walk from AAA loops without ever reaching ZZZ.
+    pub fn path_steps_pt1(&self) -> Option<u32> {
+        let start = (self.pt1_start, 0);
+        let cycle = cycle::brent(start, |state| self.next_state(state));
 
-        for (count, direction) in self.directions.iter().cycle().enumerate() {
-            if loc == self.pt1_end {
-                return count as u32;
-            }
+        // every state of the walk comes up within its prefix and one period.
+        self.states(start)
+            .take(cycle.prefix + cycle.period)
+            .position(|(node, _)| node == self.pt1_end)
+            .map(|steps| steps as u32)
+    }
 
-            loc = match direction {
-                Direction::Left => self.nodes[loc].left,
-                Direction::Right => self.nodes[loc].right,
-            };
+    /// Every ghost ends up in a cycle of states that passes Z nodes at fixed steps. Before every ghost has reached
+    /// its cycle, the ghosts are walked together. After that, they meet at the smallest number of steps that solves
+    /// the congruences of one Z step per ghost. The congruences are combined one ghost at a time, dropping
+    /// combinations that can not be satisfied. Returns [`None`] if the ghosts never meet or the steps overflow.
+    pub fn path_steps_pt2(&self) -> Option<u64> {
+        let walks: Vec<(Cycle, Vec<u64>)> = self
+            .pt2_starts
+            .iter()
+            .map(|&start| self.z_steps(start))
+            .collect();
+        let prefix = walks
+            .iter()
+            .map(|(cycle, _)| cycle.prefix)
+            .max()
+            .unwrap_or(0);
+
+        let mut ghosts: Vec<State> = self.pt2_starts.iter().map(|&start| (start, 0)).collect();
+        for steps in 0..prefix {
+            if ghosts.iter().all(|&(node, _)| self.is_z(node)) {
+                return u64::try_from(steps).ok();
+            }
+            ghosts = ghosts.iter().map(|state| self.next_state(state)).collect();
         }
 
-        0
-    }
+        let mut congruences = vec![(0, 1)];
+        for (cycle, z_steps) in &walks {
+            let period = i64::try_from(cycle.period).ok()?;
+            let z_steps = z_steps
+                .iter()
+                .map(|&steps| i64::try_from(steps).ok())
+                .collect::<Option<Vec<_>>>()?;
 
-    pub fn path_steps_pt2(&self) -> u64 {
-        self.pt2_starts
-            .iter()
-            .map(|&start| self.steps_to_z(start))
-            .reduce(num::integer::lcm)
-            .unwrap()
+            congruences = congruences
+                .iter()
+                .cartesian_product(&z_steps)
+                .filter_map(|(&congruence, &steps)| math::crt([congruence, (steps, period)]))
+                .collect();
+            congruences.sort_unstable();
+            congruences.dedup();
+        }
+
+        let prefix = u64::try_from(prefix).ok()?;
+        congruences
+            .into_iter()
+            .map(|(residue, modulus)| {
+                let (residue, modulus) =
+                    (u64::try_from(residue).ok()?, u64::try_from(modulus).ok()?);
+                let periods = prefix.saturating_sub(residue).div_ceil(modulus);
+                residue.checked_add(periods.checked_mul(modulus)?)
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()
     }
 
-    fn steps_to_z(&self, start: usize) -> u64 {
-        let mut loc = start;
+    /// Returns the cycle of the walk from `start` and the steps within its first period that end on a Z node.
+    fn z_steps(&self, start: NodeId) -> (Cycle, Vec<u64>) {
+        let start = (start, 0);
+        let cycle = cycle::brent(start, |state| self.next_state(state));
 
-        for (count, direction) in self.directions.iter().cycle().enumerate() {
-            if self.nodes[loc].last_char == 'Z' {
-                return count as u64;
-            }
+        let z_steps = self
+            .states(start)
+            .enumerate()
+            .skip(cycle.prefix)
+            .take(cycle.period)
+            .filter(|&(_, (node, _))| self.is_z(node))
+            .map(|(steps, _)| steps as u64)
+            .collect();
 
-            loc = match direction {
-                Direction::Left => self.nodes[loc].left,
-                Direction::Right => self.nodes[loc].right,
-            };
-        }
+        (cycle, z_steps)
+    }
 
-        0
+    fn is_z(&self, node: NodeId) -> bool {
+        self.graph.name(node).ends_with('Z')
     }
-}
 
-fn parse_locations(input: &str) -> HashMap<&str, usize> {
-    let mut names = HashMap::new();
+    fn states(&self, start: State) -> impl Iterator<Item = State> + '_ {
+        std::iter::successors(Some(start), |state| Some(self.next_state(state)))
+    }
 
-    for (i, line) in input.lines().enumerate() {
-        let (name, _) = line
-            .split_once(" = ")
-            .expect("couldn't split name from line");
-        names.insert(name, i);
+    fn next_state(&self, &(node, i): &State) -> State {
+        let next = self.step(node, self.directions[i]);
+        (next, (i + 1) % self.directions.len())
     }
 
-    names
+    fn step(&self, node: NodeId, direction: Direction) -> NodeId {
+        let edges = self.graph.edges(node);
+        match direction {
+            Direction::Left => edges[0].to,
+            Direction::Right => edges[1].to,
+        }
+    }
 }
 
-fn parse_nodes(input: &str, name_locations: &HashMap<&str, usize>) -> Vec<Node> {
-    let mut nodes = Vec::with_capacity(name_locations.len());
+fn parse_graph(lines: &[Line]) -> Result<Graph<String>, ParseError> {
+    let mut builder = GraphBuilder::new();
+    let mut connections = vec![];
 
-    for line in input.lines() {
+    // nodes are numbered in the order they are defined, so they are known before any edge refers to them.
+    for line in lines {
         // "AAA = (BBB, CCC)"
-        let (name, connections) = line
-            .split_once(" = ")
-            .expect("couldn't split name from line");
-
-        let connections = connections
-            .strip_prefix('(')
-            .unwrap()
-            .strip_suffix(')')
-            .unwrap();
+        let (name, connection) = line.split_once(" = ")?;
+        if name.text().is_empty() {
+            return Err(name.error("the name of a node"));
+        }
 
-        let (left, right) = connections
-            .split_once(", ")
-            .expect("couldn't split connections");
+        builder.add_node(name.text().to_string());
+        connections.push((name, connection));
+    }
 
-        let left_idx = *name_locations.get(left).expect("missing left location");
-        let right_idx = *name_locations.get(right).expect("missing right location");
+    for (name, connection) in connections {
+        let (left, right) = connection
+            .strip_prefix("(")?
+            .strip_suffix(")")?
+            .split_once(", ")?;
 
-        nodes.push(Node {
-            last_char: name.chars().last().unwrap(),
-            left: left_idx,
-            right: right_idx,
-        });
+        for next in [left, right] {
+            if builder.node(next.text()).is_none() {
+                return Err(next.error("the name of a node"));
+            }
+            builder.add_edge(name.text().to_string(), next.text().to_string(), 1);
+        }
     }
 
-    nodes
+    Ok(builder.build())
 }
 
-fn parse_directions(input: &str) -> Vec<Direction> {
-    input
-        .chars()
-        .map(|c| match c {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => panic!("invalid direction"),
+fn parse_directions(line: Line) -> Result<Vec<Direction>, ParseError> {
+    if line.text().is_empty() {
+        return Err(line.error("a line of directions"));
+    }
+
+    line.chars()
+        .map(|(c, part)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(part.error("a direction \"L\" or \"R\"")),
         })
         .collect()
 }
 
 impl FromStr for Input {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (directions, nodes) = s.split_once("\n\n").unwrap();
-
-        let directions = parse_directions(directions);
+        let mut paragraphs = parse::paragraphs(s);
+        let directions = paragraphs
+            .next()
+            .map_or(Line::new(1, ""), |paragraph| paragraph[0]);
+        let nodes = paragraphs.next().unwrap_or_default();
 
-        let name_locations = parse_locations(nodes);
-        let nodes = parse_nodes(nodes, &name_locations);
+        let directions = parse_directions(directions)?;
+        let graph = parse_graph(&nodes)?;
 
-        let pt1_start = *name_locations.get("AAA").unwrap_or(&0);
-        let pt1_end = *name_locations.get("ZZZ").unwrap_or(&0);
+        let pt1_start = graph.node("AAA").unwrap_or(0);
+        let pt1_end = graph.node("ZZZ").unwrap_or(0);
 
-        let pt2_starts = name_locations
-            .iter()
-            .filter_map(|(&key, &value)| {
-                if key.ends_with('A') {
-                    Some(value)
-                } else {
-                    None
-                }
-            })
+        let pt2_starts = graph
+            .nodes()
+            .filter(|&node| graph.name(node).ends_with('A'))
             .collect();
 
         Ok(Self {
             directions,
-            nodes,
+            graph,
             pt1_start,
             pt1_end,
             pt2_starts,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_parse_errors() {
+        let error = |input: &str| input.parse::<Input>().unwrap_err();
+
+        let unknown_node = error("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, CCC)\nZZZ = (ZZZ, ZZZ)\n");
+        assert_eq!((unknown_node.line, unknown_node.column), (4, 13));
+        assert_eq!(unknown_node.found, "CCC");
+
+        let direction = error("LRX\n\nAAA = (AAA, AAA)\n");
+        assert_eq!((direction.line, direction.column), (1, 3));
+    }
+
+    #[test]
+    fn detects_unreachable_targets() {
+        let input: Input = "L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"
+            .parse()
+            .unwrap();
+        assert_eq!(input.path_steps_pt1(), None);
+        assert_eq!(input.path_steps_pt2(), None);
+    }
+
+    #[test]
+    fn agrees_with_walking_all_ghosts() {
+        let mut rng = crate::generate::Rng::new(8);
+
+        for _ in 0..200 {
+            // up to 6 nodes and 3 directions give at most 18 states per ghost, so up to 3 ghosts meet within the
+            // longest prefix plus the product of their periods, or never.
+            let directions: String = (0..rng.range(1, 3))
+                .map(|_| *rng.pick(&['L', 'R']))
+                .collect();
+            let names: Vec<String> = (0..rng.range(2, 6))
+                .map(|i| {
+                    let letter = (b'B' + i as u8) as char;
+                    format!("{letter}{letter}{}", rng.pick(&['A', 'X', 'Z']))
+                })
+                .collect();
+            if names.iter().filter(|name| name.ends_with('A')).count() > 3 {
+                continue;
+            }
+
+            let nodes: String = names
+                .iter()
+                .map(|name| {
+                    let (left, right) = (rng.pick(&names), rng.pick(&names));
+                    format!("{name} = ({left}, {right})\n")
+                })
+                .collect();
+            let input: Input = format!("{directions}\n\n{nodes}").parse().unwrap();
+
+            let mut ghosts: Vec<State> = input.pt2_starts.iter().map(|&start| (start, 0)).collect();
+            let walked = (0..6000_u64).find(|_| {
+                let all_z = ghosts.iter().all(|&(node, _)| input.is_z(node));
+                ghosts = ghosts.iter().map(|state| input.next_state(state)).collect();
+                all_z
+            });
+
+            if !input.pt2_starts.is_empty() {
+                assert_eq!(input.path_steps_pt2(), walked, "{directions}\n{nodes}");
+            }
+        }
+    }
+}
@@ -1,10 +1,9 @@
 use itertools::Itertools;
 
-pub fn parse_input_line(input: &str) -> Vec<i64> {
-    input
-        .split_ascii_whitespace()
-        .map(|n| n.parse().unwrap())
-        .collect()
+use crate::parse::{Line, ParseError};
+
+pub fn parse_input_line(line: Line) -> Result<Vec<i64>, ParseError> {
+    line.signed()
 }
 
 pub fn extrapolate_sequence(sequence: &[i64], reverse: bool) -> i64 {
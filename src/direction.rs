@@ -0,0 +1,115 @@
+/// A compass direction on a grid.
+///
+/// Directions follow the grid convention of `y` pointing down, so [`Direction::North`] has the offset `(0, -1)`.
+/// [`Direction::ALL`] lists the directions clockwise starting north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// A turn by 90 degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise starting north.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise starting north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The step in this direction as `(dx, dy)`.
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn, counter-clockwise for negative `eighths`.
+    pub fn rotate(self, eighths: i32) -> Self {
+        Self::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.rotate(-2),
+            Turn::Right => self.rotate(2),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_follow_grid_convention() {
+        assert_eq!(Direction::North.offset(), (0, -1));
+        assert_eq!(Direction::SouthWest.offset(), (-1, 1));
+        assert_eq!(
+            Direction::ORTHOGONAL.map(Direction::offset),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)]
+        );
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.is_diagonal() == (d.offset().0 * d.offset().1 != 0)));
+    }
+
+    #[test]
+    fn turns_and_reverses() {
+        assert_eq!(Direction::North.turn(Turn::Right), Direction::East);
+        assert_eq!(Direction::North.turn(Turn::Left), Direction::West);
+        assert_eq!(Direction::NorthWest.rotate(1), Direction::North);
+        assert_eq!(Direction::North.rotate(-9), Direction::NorthWest);
+
+        for direction in Direction::ALL {
+            let (dx, dy) = direction.offset();
+            assert_eq!(direction.opposite().offset(), (-dx, -dy));
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.turn(Turn::Left).turn(Turn::Right), direction);
+            assert_eq!(
+                direction.turn(Turn::Right).turn(Turn::Right),
+                direction.opposite()
+            );
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::Day;
+
+/// A small, seedable pseudo random number generator (SplitMix64).
+/// Not suitable for cryptography, but fast and stable across platforms, so a seed always produces the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `min..=max`.
+    pub fn range(&mut self, min: u64, max: u64) -> u64 {
+        assert!(min <= max, "empty range");
+        match (max - min).checked_add(1) {
+            Some(span) => min + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Returns a signed number in `min..=max`.
+    pub fn range_i64(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "empty range");
+        let span = max.abs_diff(min);
+        min.wrapping_add_unsigned(self.range(0, span))
+    }
+
+    /// Returns `true` with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1, denominator) <= numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0, i as u64) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Days that have an input generator.
+pub const DAYS: [u8; 5] = [2, 4, 6, 7, 9];
+
+/// Generates an input for a day with `size` entries (games, cards, races, hands or sequences).
+/// Returns [`None`] if there is no generator for the day.
+pub fn for_day(day: Day, size: usize, rng: &mut Rng) -> Option<String> {
+    match day.into_inner() {
+        2 => Some(games(size, rng)),
+        4 => Some(scratchcards(size, rng)),
+        6 => Some(races(size, rng)),
+        7 => Some(hands(size, rng)),
+        9 => Some(sequences(size, rng)),
+        _ => None,
+    }
+}
+
+/// Cube games like `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green`.
+pub fn games(size: usize, rng: &mut Rng) -> String {
+    let mut colors = ["red", "green", "blue"];
+
+    (1..=size)
+        .map(|number| {
+            let rounds: Vec<String> = (0..rng.range(1, 6))
+                .map(|_| {
+                    rng.shuffle(&mut colors);
+                    let n_colors = rng.range(1, 3) as usize;
+                    colors[..n_colors]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.range(1, 20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+
+            format!("Game {number}: {}\n", rounds.join("; "))
+        })
+        .collect()
+}
+
+/// Scratchcards like `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+/// Matches are kept rare enough that the number of copies in part two stays bounded, and no card wins copies of
+/// cards past the end of the table.
+pub fn scratchcards(size: usize, rng: &mut Rng) -> String {
+    let number_width = size.to_string().len();
+
+    (1..=size)
+        .map(|number| {
+            let mut pool: Vec<u64> = (1..=99).collect();
+            rng.shuffle(&mut pool);
+
+            let (winning, rest) = pool.split_at(10);
+            let max_matches = (size - number).min(4) as u64;
+            let matches = if rng.chance(1, 5) {
+                rng.range(0, max_matches)
+            } else {
+                0
+            };
+
+            let mut numbers: Vec<u64> = winning[..matches as usize]
+                .iter()
+                .chain(&rest[..25 - matches as usize])
+                .copied()
+                .collect();
+            rng.shuffle(&mut numbers);
+
+            let format_numbers = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            format!(
+                "Card {number:>number_width$}: {} | {}\n",
+                format_numbers(winning),
+                format_numbers(&numbers)
+            )
+        })
+        .collect()
+}
+
+/// Boat races as a `Time:` and a `Distance:` line. Every race can be won.
+/// Part two reads all races as a single one, which only fits into `u64` for up to four races, like in the real input.
+/// For those sizes, races are drawn until that single race can be won as well.
+pub fn races(size: usize, rng: &mut Rng) -> String {
+    let mut draw = || -> Vec<(u64, u64)> {
+        (0..size)
+            .map(|_| {
+                let time = rng.range(7, 99);
+                (time, rng.range(time / 2, time * time / 4 - 1))
+            })
+            .collect()
+    };
+
+    let mut races = draw();
+    for _ in 0..1000 {
+        if is_joined_race_winnable(&races).unwrap_or(true) {
+            break;
+        }
+        races = draw();
+    }
+
+    let width = 5;
+    let times: String = races.iter().map(|(t, _)| format!("{t:>width$}")).collect();
+    let distances: String = races.iter().map(|(_, d)| format!("{d:>width$}")).collect();
+
+    format!("Time:    {times}\nDistance:{distances}\n")
+}
+
+/// Returns [`None`] if the joined race does not fit into `u64`.
+fn is_joined_race_winnable(races: &[(u64, u64)]) -> Option<bool> {
+    let join = |values: Vec<u64>| -> Option<u64> {
+        values
+            .iter()
+            .map(u64::to_string)
+            .collect::<String>()
+            .parse()
+            .ok()
+    };
+
+    let time = u128::from(join(races.iter().map(|(t, _)| *t).collect())?);
+    let distance = u128::from(join(races.iter().map(|(_, d)| *d).collect())?);
+
+    Some(time * time > 4 * (distance + 1))
+}
+
+/// Camel card hands like `32T3K 765`.
+/// Total winnings are summed into `u32`, which holds for up to about 2900 hands.
+pub fn hands(size: usize, rng: &mut Rng) -> String {
+    const CARDS: &[char] = &[
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+
+    (0..size)
+        .map(|_| {
+            let cards: String = (0..5).map(|_| *rng.pick(CARDS)).collect();
+            format!("{cards} {}\n", rng.range(1, 1000))
+        })
+        .collect()
+}
+
+/// OASIS histories of 21 values, each generated by a polynomial of degree up to 6 so it can be extrapolated.
+pub fn sequences(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            // the first value of every row of differences, the last row is constant.
+            let degree = rng.range(0, 6) as usize;
+            let mut differences: Vec<i64> = (0..=degree).map(|_| rng.range_i64(-9, 9)).collect();
+
+            let mut values = vec![];
+            for _ in 0..21 {
+                values.push(differences[0].to_string());
+                for i in 0..degree {
+                    differences[i] += differences[i + 1];
+                }
+            }
+
+            format!("{}\n", values.join(" "))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        day, day2_cubes::Game, day4_cards::Card, day6_boats, day7_cards::Hand, day9_oasis, parse,
+    };
+
+    #[test]
+    fn generates_parsable_inputs() {
+        let mut rng = Rng::new(42);
+
+        let games = games(50, &mut rng);
+        assert_eq!(games.lines().count(), 50);
+        assert!(games.lines().all(|line| line.parse::<Game>().is_ok()));
+
+        let cards = scratchcards(50, &mut rng);
+        assert_eq!(cards.lines().count(), 50);
+        assert!(cards.lines().all(|line| line.parse::<Card>().is_ok()));
+
+        let hands = hands(50, &mut rng);
+        assert!(hands.lines().all(|line| line.parse::<Hand>().is_ok()));
+
+        let races = races(4, &mut rng);
+        assert_eq!(day6_boats::parse_day6_input(&races).unwrap().len(), 4);
+        assert!(
+            day6_boats::parse_day6_input_pt2(&races)
+                .unwrap()
+                .number_of_winning_combos()
+                > 1
+        );
+
+        let sequences = sequences(50, &mut rng);
+        for line in parse::lines(&sequences) {
+            let sequence = day9_oasis::parse_input_line(line).unwrap();
+            assert_eq!(sequence.len(), 21);
+            day9_oasis::extrapolate_sequence(&sequence, false);
+        }
+    }
+
+    #[test]
+    fn is_deterministic() {
+        let generate = |seed| for_day(day!(7), 20, &mut Rng::new(seed));
+        assert_eq!(generate(1), generate(1));
+        assert_ne!(generate(1), generate(2));
+        assert_eq!(for_day(day!(1), 20, &mut Rng::new(1)), None);
+    }
+
+    #[test]
+    fn scratchcards_stay_within_table() {
+        let cards: Vec<Card> = scratchcards(200, &mut Rng::new(7))
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect();
+
+        for (i, card) in cards.iter().enumerate() {
+            assert!(i + card.matches() as usize <= cards.len());
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Module for polygons on the integer lattice.
+//!
+//! A polygon is a slice of points in boundary order, either only its vertices or every lattice point along its
+//! boundary. Both describe the same shape, so all functions accept either. The closing edge back to the first point is
+//! implied, repeating the first point at the end is allowed as well.
+
+use std::ops::{Add, Sub};
+
+use crate::{direction::Direction, grid::Position, math::gcd};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Adds `other` if the sum lies within a grid of `width` by `height` cells, returns [`None`] otherwise.
+    pub fn checked_add(self, other: Point, width: usize, height: usize) -> Option<Point> {
+        let sum = Point::new(self.x.checked_add(other.x)?, self.y.checked_add(other.y)?);
+        sum.position()
+            .is_some_and(|(x, y)| x < width && y < height)
+            .then_some(sum)
+    }
+
+    /// The grid position of this point, [`None`] if a coordinate is negative.
+    pub fn position(self) -> Option<Position> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+/// The step in a direction.
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        let (dx, dy) = direction.offset();
+        Self::new(dx as i64, dy as i64)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + Point::from(direction)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Iterates the edges of a polygon, including the closing edge.
+fn edges(polygon: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    let polygon = match polygon {
+        [first, rest @ .., last] if first == last => &polygon[..=rest.len()],
+        _ => polygon,
+    };
+
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Twice the signed area after the shoelace formula, positive for counter-clockwise polygons (with `y` pointing up).
+/// Twice the area of a lattice polygon is always an integer.
+pub fn signed_double_area(polygon: &[Point]) -> i64 {
+    edges(polygon).map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+}
+
+/// Twice the area of a polygon.
+pub fn double_area(polygon: &[Point]) -> u64 {
+    signed_double_area(polygon).unsigned_abs()
+}
+
+/// The area of a polygon.
+#[allow(clippy::cast_precision_loss)]
+pub fn area(polygon: &[Point]) -> f64 {
+    double_area(polygon) as f64 / 2.0
+}
+
+/// The euclidean length of the boundary of a polygon.
+#[allow(clippy::cast_precision_loss)]
+pub fn perimeter(polygon: &[Point]) -> f64 {
+    edges(polygon)
+        .map(|(a, b)| {
+            let d = b - a;
+            ((d.x * d.x + d.y * d.y) as f64).sqrt()
+        })
+        .sum()
+}
+
+/// The number of lattice points on the boundary of a polygon, counting every vertex once.
+/// An edge from `a` to `b` passes through `gcd(|dx|, |dy|)` lattice points besides `a`.
+pub fn boundary_points(polygon: &[Point]) -> u64 {
+    edges(polygon)
+        .map(|(a, b)| {
+            let d = b - a;
+            gcd(d.x.unsigned_abs(), d.y.unsigned_abs())
+        })
+        .sum()
+}
+
+/// The number of lattice points strictly inside a simple polygon.
+/// After Pick's theorem `A = i + b/2 - 1`, so `i = (2A - b + 2) / 2`.
+pub fn interior_points(polygon: &[Point]) -> u64 {
+    (double_area(polygon) + 2).saturating_sub(boundary_points(polygon)) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(i64, i64)]) -> Vec<Point> {
+        points.iter().copied().map(Point::from).collect()
+    }
+
+    #[test]
+    fn adds_within_grid_bounds() {
+        let corner = Point::new(2, 0);
+
+        assert_eq!(corner + Direction::SouthWest, Point::new(1, 1));
+        assert_eq!(
+            corner.checked_add(Direction::West.into(), 3, 2),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(corner.checked_add(Direction::North.into(), 3, 2), None);
+        assert_eq!(corner.checked_add(Direction::East.into(), 3, 2), None);
+        assert_eq!(
+            corner.checked_add(Point::new(-2, 1), 3, 2),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(
+            Point::new(i64::MAX, 0).checked_add(Point::new(1, 0), 3, 2),
+            None
+        );
+        assert_eq!(Point::from((4_usize, 5_usize)).position(), Some((4, 5)));
+    }
+
+    #[test]
+    fn computes_area_of_triangle() {
+        let triangle = polygon(&[(1, 2), (3, 7), (5, 4)]);
+
+        assert_eq!(signed_double_area(&triangle), -16);
+        assert_eq!(area(&triangle), 8.0);
+        assert_eq!(boundary_points(&triangle), 1 + 1 + 2);
+        assert_eq!(interior_points(&triangle), 7);
+    }
+
+    #[test]
+    fn handles_vertex_only_and_full_boundary_lists() {
+        let vertices = polygon(&[(0, 0), (3, 0), (3, 2), (0, 2)]);
+        let boundary = polygon(&[
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (3, 1),
+            (3, 2),
+            (2, 2),
+            (1, 2),
+            (0, 2),
+            (0, 1),
+        ]);
+
+        for rectangle in [&vertices, &boundary] {
+            assert_eq!(double_area(rectangle), 12);
+            assert_eq!(perimeter(rectangle), 10.0);
+            assert_eq!(boundary_points(rectangle), 10);
+            assert_eq!(interior_points(rectangle), 2);
+        }
+    }
+
+    #[test]
+    fn ignores_repeated_closing_point() {
+        let open = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        let closed = polygon(&[(0, 0), (4, 0), (0, 4), (0, 0)]);
+
+        assert_eq!(double_area(&open), double_area(&closed));
+        assert_eq!(boundary_points(&open), boundary_points(&closed));
+        assert_eq!(boundary_points(&closed), 12);
+        assert_eq!(interior_points(&closed), 3);
+    }
+
+    #[test]
+    fn handles_concave_polygons() {
+        // a U shape, clockwise with y pointing down.
+        let u = polygon(&[
+            (0, 0),
+            (2, 0),
+            (2, 4),
+            (4, 4),
+            (4, 0),
+            (6, 0),
+            (6, 6),
+            (0, 6),
+        ]);
+
+        assert_eq!(signed_double_area(&u), 56);
+        assert_eq!(boundary_points(&u), 32);
+        assert_eq!(interior_points(&u), 13);
+    }
+
+    #[test]
+    fn handles_degenerate_polygons() {
+        assert_eq!(double_area(&[]), 0);
+        assert_eq!(boundary_points(&[Point::new(2, 3)]), 0);
+        assert_eq!(perimeter(&polygon(&[(0, 0), (3, 4)])), 10.0);
+        assert_eq!(interior_points(&polygon(&[(0, 0), (5, 0)])), 0);
+    }
+}
@@ -0,0 +1,468 @@
+//! Module for directed graphs between named nodes.
+//!
+//! Nodes are numbered `0..len` in the order their names are first seen, so algorithms can keep per-node state in
+//! plain `Vec`s. Edges are stored compactly in one `Vec` sorted by source, the edges of a node keep the order they
+//! were added in.
+
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::{self, Display},
+    hash::Hash,
+    ops::{Add, Range},
+};
+
+/// The index of a node in a [`Graph`].
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<W> {
+    pub to: NodeId,
+    pub weight: W,
+}
+
+/// A cycle that prevents a topological order, as the nodes along it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub nodes: Vec<NodeId>,
+}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph has a cycle through {} nodes", self.nodes.len())
+    }
+}
+
+impl std::error::Error for Cycle {}
+
+/// Collects named nodes and edges for a [`Graph`].
+#[derive(Debug, Clone)]
+pub struct GraphBuilder<N, W = u32> {
+    names: Vec<N>,
+    index: HashMap<N, NodeId>,
+    edges: Vec<(NodeId, Edge<W>)>,
+}
+
+impl<N: Eq + Hash + Clone, W> Default for GraphBuilder<N, W> {
+    fn default() -> Self {
+        Self {
+            names: vec![],
+            index: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone, W> GraphBuilder<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of a node that was already added.
+    pub fn node<Q>(&self, name: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.get(name).copied()
+    }
+
+    /// Adds a node without edges, or returns the id of the node with this name.
+    pub fn add_node(&mut self, name: N) -> NodeId {
+        if let Some(&id) = self.index.get(&name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.clone());
+        self.index.insert(name, id);
+        id
+    }
+
+    /// Adds an edge from `from` to `to`, adding both nodes if needed.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges.push((from, Edge { to, weight }));
+    }
+
+    /// Adds an edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_edge(a.clone(), b.clone(), weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn build(mut self) -> Graph<N, W> {
+        // a stable sort keeps the edges of every node in insertion order.
+        self.edges.sort_by_key(|(from, _)| *from);
+
+        let mut offsets = vec![0; self.names.len() + 1];
+        for (from, _) in &self.edges {
+            offsets[from + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        Graph {
+            names: self.names,
+            index: self.index,
+            offsets,
+            edges: self.edges.into_iter().map(|(_, edge)| edge).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = u32> {
+    names: Vec<N>,
+    index: HashMap<N, NodeId>,
+    /// the edges of node `i` are `edges[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    edges: Vec<Edge<W>>,
+}
+
+impl<N: Eq + Hash + Clone, W> Graph<N, W> {
+    /// Builds a graph from `(from, to, weight)` edges between named nodes.
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N, W)>) -> Self {
+        let mut builder = GraphBuilder::new();
+        for (from, to, weight) in edges {
+            builder.add_edge(from, to, weight);
+        }
+        builder.build()
+    }
+
+    pub fn node<Q>(&self, name: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.get(name).copied()
+    }
+}
+
+impl<N, W> Graph<N, W> {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<NodeId> {
+        0..self.len()
+    }
+
+    pub fn name(&self, node: NodeId) -> &N {
+        &self.names[node]
+    }
+
+    /// The outgoing edges of a node, in the order they were added.
+    pub fn edges(&self, node: NodeId) -> &[Edge<W>] {
+        &self.edges[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    pub fn neighbours(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges(node).iter().map(|edge| edge.to)
+    }
+
+    /// The number of edges on a shortest path from `start` to every node, ignoring weights.
+    pub fn bfs(&self, start: NodeId) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        let mut queue = VecDeque::from([start]);
+        distances[start] = Some(0);
+
+        while let Some(node) = queue.pop_front() {
+            let next = distances[node].map(|distance| distance + 1);
+
+            for neighbour in self.neighbours(node) {
+                if distances[neighbour].is_none() {
+                    distances[neighbour] = next;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The weight of a lightest path from `start` to every node. Weights must not be negative.
+    pub fn dijkstra(&self, start: NodeId) -> Vec<Option<W>>
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+    {
+        let mut distances = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((W::default(), start))]);
+        distances[start] = Some(W::default());
+
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+
+            for edge in self.edges(node) {
+                let next = distance + edge.weight;
+                if distances[edge.to].is_none_or(|best| next < best) {
+                    distances[edge.to] = Some(next);
+                    queue.push(Reverse((next, edge.to)));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// A lightest path from `start` to `goal` as its weight and nodes, or [`None`] if `goal` can't be reached.
+    /// The `heuristic` estimates the weight from a node to `goal` and must never overestimate it.
+    pub fn astar(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        mut heuristic: impl FnMut(NodeId) -> W,
+    ) -> Option<(W, Vec<NodeId>)>
+    where
+        W: Copy + Ord + Add<Output = W> + Default,
+    {
+        let mut distances = vec![None; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((heuristic(start), start))]);
+        distances[start] = Some(W::default());
+
+        while let Some(Reverse((_, node))) = queue.pop() {
+            let distance = distances[node]?;
+
+            if node == goal {
+                let mut path = vec![goal];
+                while let Some(before) = previous[path[path.len() - 1]] {
+                    path.push(before);
+                }
+                path.reverse();
+                return Some((distance, path));
+            }
+
+            for edge in self.edges(node) {
+                let next = distance + edge.weight;
+                if distances[edge.to].is_none_or(|best| next < best) {
+                    distances[edge.to] = Some(next);
+                    previous[edge.to] = Some(node);
+                    queue.push(Reverse((next + heuristic(edge.to), edge.to)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Groups the nodes that are connected when edges are followed in either direction. Components are ordered by
+    /// their smallest node and list their nodes in ascending order.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut parents: Vec<NodeId> = self.nodes().collect();
+
+        fn root(parents: &mut [NodeId], mut node: NodeId) -> NodeId {
+            while parents[node] != node {
+                parents[node] = parents[parents[node]];
+                node = parents[node];
+            }
+            node
+        }
+
+        for node in self.nodes() {
+            for neighbour in self.neighbours(node) {
+                let (a, b) = (root(&mut parents, node), root(&mut parents, neighbour));
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut components: Vec<Vec<NodeId>> = vec![];
+        let mut component_of = vec![None; self.len()];
+
+        for node in self.nodes() {
+            let root = root(&mut parents, node);
+            let i = *component_of[root].get_or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[i].push(node);
+        }
+
+        components
+    }
+
+    /// Orders the nodes so that every edge points from an earlier to a later node, or returns a [`Cycle`] if there is
+    /// no such order.
+    pub fn toposort(&self) -> Result<Vec<NodeId>, Cycle> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum State {
+            New,
+            Open,
+            Done,
+        }
+
+        let mut states = vec![State::New; self.len()];
+        let mut order = Vec::with_capacity(self.len());
+
+        for start in self.nodes() {
+            if states[start] != State::New {
+                continue;
+            }
+
+            // depth first, with the next edge to follow for every node on the path.
+            let mut path = vec![(start, 0)];
+            states[start] = State::Open;
+
+            while let Some((node, next_edge)) = path.last_mut() {
+                let node = *node;
+
+                let Some(edge) = self.edges(node).get(*next_edge) else {
+                    states[node] = State::Done;
+                    order.push(node);
+                    path.pop();
+                    continue;
+                };
+                *next_edge += 1;
+
+                match states[edge.to] {
+                    State::New => {
+                        states[edge.to] = State::Open;
+                        path.push((edge.to, 0));
+                    }
+                    State::Open => {
+                        let first = path.iter().position(|&(n, _)| n == edge.to).unwrap();
+                        let nodes = path[first..].iter().map(|&(n, _)| n).collect();
+                        return Err(Cycle { nodes });
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Graph<&'static str> {
+        Graph::from_edges([
+            ("a", "b", 7),
+            ("a", "c", 2),
+            ("c", "b", 3),
+            ("b", "d", 1),
+            ("c", "d", 8),
+            ("e", "f", 1),
+        ])
+    }
+
+    #[test]
+    fn builds_from_named_edges() {
+        let graph = example();
+        let [a, b, c] = ["a", "b", "c"].map(|name| graph.node(name).unwrap());
+
+        assert_eq!(graph.len(), 6);
+        assert_eq!((a, b, c), (0, 1, 2));
+        assert_eq!(graph.name(c), &"c");
+        assert_eq!(graph.neighbours(a).collect::<Vec<_>>(), [b, c]);
+        assert_eq!(graph.edges(c)[1], Edge { to: 3, weight: 8 });
+        assert_eq!(graph.edges(graph.node("f").unwrap()), &[]);
+        assert_eq!(graph.node("x"), None);
+
+        let mut builder = GraphBuilder::new();
+        builder.add_node("lonely");
+        builder.add_undirected_edge("x", "y", 4);
+        let graph = builder.build();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.neighbours(2).collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let graph = example();
+
+        assert_eq!(
+            graph.bfs(0),
+            [Some(0), Some(1), Some(1), Some(2), None, None]
+        );
+        assert_eq!(
+            graph.dijkstra(0),
+            [Some(0), Some(5), Some(2), Some(6), None, None]
+        );
+        assert_eq!(graph.astar(0, 3, |_| 0), Some((6, vec![0, 2, 1, 3])));
+        assert_eq!(graph.astar(0, 4, |_| 0), None);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra_on_grids() {
+        let mut rng = crate::generate::Rng::new(47);
+        let size = 8_u32;
+
+        for _ in 0..50 {
+            let mut builder = GraphBuilder::new();
+            for y in 0..size {
+                for x in 0..size {
+                    if x + 1 < size {
+                        builder.add_undirected_edge((x, y), (x + 1, y), rng.range(1, 9) as u32);
+                    }
+                    if y + 1 < size {
+                        builder.add_undirected_edge((x, y), (x, y + 1), rng.range(1, 9) as u32);
+                    }
+                }
+            }
+            let graph = builder.build();
+            let goal = (size - 1, size - 1);
+            let goal_id = graph.node(&goal).unwrap();
+
+            // every edge weighs at least 1, so the manhattan distance never overestimates.
+            let (weight, path) = graph
+                .astar(0, goal_id, |node| {
+                    let (x, y) = *graph.name(node);
+                    goal.0 - x + goal.1 - y
+                })
+                .unwrap();
+
+            assert_eq!(Some(weight), graph.dijkstra(0)[goal_id]);
+            assert_eq!((path[0], path[path.len() - 1]), (0, goal_id));
+        }
+    }
+
+    #[test]
+    fn groups_connected_components() {
+        assert_eq!(example().components(), [vec![0, 1, 2, 3], vec![4, 5]]);
+
+        let mut builder = GraphBuilder::<u8>::new();
+        builder.add_edge(3, 1, 1);
+        builder.add_node(2);
+        builder.add_edge(4, 1, 1);
+        assert_eq!(builder.build().components(), [vec![0, 1, 3], vec![2]]);
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = example();
+        let order = graph.toposort().unwrap();
+        let position = |node: NodeId| order.iter().position(|&n| n == node).unwrap();
+
+        assert_eq!(order.len(), graph.len());
+        for node in graph.nodes() {
+            for neighbour in graph.neighbours(node) {
+                assert!(position(node) < position(neighbour));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let graph = Graph::from_edges([("a", "b", 1), ("b", "c", 1), ("c", "d", 1), ("d", "b", 1)]);
+        let cycle = graph.toposort().unwrap_err();
+
+        assert_eq!(cycle.nodes, [1, 2, 3]);
+        assert_eq!(cycle.to_string(), "graph has a cycle through 3 nodes");
+    }
+}
@@ -0,0 +1,289 @@
+//! Module for rectangular 2D puzzle maps.
+//!
+//! Cells are stored row by row in a single `Vec`, positions are `(x, y)` pairs with `(0, 0)` in the top left corner.
+
+use std::{
+    fmt::{self, Display},
+    iter::StepBy,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{
+    direction::Direction,
+    parse::{self, Line, ParseError},
+};
+
+/// A position in a grid as `(x, y)`.
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "grids must be at least one cell wide");
+
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid from cells in row order. Panics if `cells` does not fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "cells do not fill rows of width {width}"
+        );
+
+        Self {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    /// Parses a character map with one row per line, mapping every character to a cell.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(s, |c, _| Ok(f(c)))
+    }
+
+    /// Parses a character map like [`Grid::parse`], where `f` can reject a character at its location.
+    pub fn try_parse(
+        s: &str,
+        mut f: impl FnMut(char, Line) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+
+        for line in parse::lines(s) {
+            let len_before = cells.len();
+            for (c, part) in line.chars() {
+                cells.push(f(c, part)?);
+            }
+            let found = cells.len() - len_before;
+
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    let row = line.chars().nth(expected).map_or(line, |(_, rest)| rest);
+                    return Err(row.error(format!("a row of {expected} cells like the first line")));
+                }
+                Some(_) => {}
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::from_cells(width, cells)),
+            _ => Err(Line::new(1, s.lines().next().unwrap_or_default()).error("a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `position` by `(dx, dy)`, returns [`None`] if that leaves the grid.
+    pub fn step(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to four orthogonal neighbours of a position that lie within the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()))
+    }
+
+    /// The up to eight neighbours of a position, including diagonals, that lie within the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> slice::ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> StepBy<slice::Iter<'_, T>> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Iterates all positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Iterates all cells in row order together with their position.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns the position of the first cell in row order that matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find_map(|(position, cell)| predicate(cell).then_some(position))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(self.contains(position), "{position:?} is out of bounds");
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(self.contains(position), "{position:?} is out of bounds");
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+/// Draws one line per row, with every cell in its own `Display` format.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c).unwrap()
+    }
+
+    #[test]
+    fn parses_char_maps() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+    }
+
+    #[test]
+    fn rejects_ragged_and_empty_maps() {
+        let error = Grid::parse("ab\nc\n", |c| c).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of 2 cells like the first line");
+
+        let error = Grid::parse("ab\nabcd\n", |c| c).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (2, 3, "c")
+        );
+
+        assert_eq!(Grid::parse("", |c| c).unwrap_err().expected, "a grid");
+    }
+
+    #[test]
+    fn locates_rejected_cells() {
+        let error = Grid::try_parse("12\n3x", |c, part| {
+            c.to_digit(10).ok_or_else(|| part.error("a digit"))
+        })
+        .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.source, "3x");
+    }
+
+    #[test]
+    fn finds_neighbours_within_bounds() {
+        let grid = example();
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_8((1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+        assert_eq!(grid.step((2, 1), (1, 0)), None);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let mut grid = example();
+        grid[(1, 1)] = 'x';
+
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "bx");
+        assert_eq!(grid.find(|c| *c == 'x'), Some((1, 1)));
+        assert_eq!(grid.map(|c| c.is_alphabetic()).column(2).count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_grids_without_columns() {
+        Grid::new(0, 3, '.');
+    }
+}
@@ -0,0 +1,375 @@
+//! Module for ranges of integers and sets of them.
+//!
+//! An [`Interval`] is stored half-open as `start..end`, but can be built from closed bounds `first..=last` as well.
+//! An [`IntervalSet`] is kept normalized: its intervals are sorted, non-empty, and neither overlap nor touch.
+
+use std::fmt::Debug;
+
+/// Integers that can be the bounds of an interval.
+pub trait Integer: Copy + Ord + Debug {
+    /// Signed type to shift intervals by.
+    type Offset: Copy;
+
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_offset(self, offset: Self::Offset) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $offset:ty, $add_offset:ident);* $(;)?) => {
+        $(
+            impl Integer for $t {
+                type Offset = $offset;
+
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_offset(self, offset: Self::Offset) -> Option<Self> {
+                    <$t>::$add_offset(self, offset)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer! {
+    u32 => i32, checked_add_signed;
+    u64 => i64, checked_add_signed;
+    usize => isize, checked_add_signed;
+    i32 => i32, checked_add;
+    i64 => i64, checked_add;
+    isize => isize, checked_add;
+}
+
+/// The integers in `start..end`, empty if `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// The integers in `start..end`.
+    pub fn half_open(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The integers in `first..=last`. Panics if `last` is the largest value of `T`.
+    pub fn closed(first: T, last: T) -> Self {
+        let end = last
+            .checked_add(T::ONE)
+            .expect("closed interval ends at the largest value");
+        Self::half_open(first, end)
+    }
+
+    /// The `len` integers starting at `start`. Panics on overflow.
+    pub fn with_len(start: T, len: T) -> Self {
+        let end = start.checked_add(len).expect("interval end overflows");
+        Self::half_open(start, end)
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The exclusive end.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Returns the overlap of both intervals, which may be empty.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::half_open(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Returns `true` if both intervals share at least one value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Moves the interval by `offset`. Panics if a bound leaves the range of `T`.
+    pub fn shift(&self, offset: T::Offset) -> Self {
+        let shift = |value: T| value.checked_offset(offset).expect("shift overflows");
+        Self::half_open(shift(self.start), shift(self.end))
+    }
+}
+
+/// A normalized set of integers, stored as sorted and disjoint intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Iterates the disjoint intervals of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(Interval::start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // intervals that overlap or touch `interval` are merged into it.
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| {
+                Interval::half_open(merged.start.min(other.start), merged.end.max(other.end))
+            });
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut intersection = vec![];
+
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let overlap = a.intersection(&b);
+
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // overlaps of disjoint, sorted intervals are disjoint and sorted as well.
+        Self {
+            intervals: intersection,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = vec![];
+
+        for interval in &self.intervals {
+            let mut start = interval.start;
+
+            for removed in other.intervals.iter().filter(|r| r.overlaps(interval)) {
+                if removed.start > start {
+                    difference.push(Interval::half_open(start, removed.start));
+                }
+                start = start.max(removed.end);
+            }
+
+            if start < interval.end {
+                difference.push(Interval::half_open(start, interval.end));
+            }
+        }
+
+        Self {
+            intervals: difference,
+        }
+    }
+
+    /// Moves every value of the set by `offset`. Panics if a value leaves the range of `T`.
+    pub fn shift(&self, offset: T::Offset) -> Self {
+        Self {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(offset))
+                .collect(),
+        }
+    }
+
+    /// Splits the set into the values inside and outside of `interval`.
+    pub fn split(&self, interval: Interval<T>) -> (Self, Self) {
+        let by: Self = [interval].into_iter().collect();
+        (self.intersection(&by), self.difference(&by))
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::generate::Rng;
+
+    const DOMAIN: u64 = 48;
+
+    fn random_set(rng: &mut Rng) -> IntervalSet<u64> {
+        (0..rng.range(0, 5))
+            .map(|_| {
+                let start = rng.range(0, DOMAIN - 1);
+                Interval::half_open(start, rng.range(start, DOMAIN))
+            })
+            .collect()
+    }
+
+    fn values(set: &IntervalSet<u64>) -> BTreeSet<u64> {
+        (0..DOMAIN + 8).filter(|v| set.contains(*v)).collect()
+    }
+
+    fn assert_normalized(set: &IntervalSet<u64>) {
+        assert!(set.iter().all(|interval| !interval.is_empty()));
+        for (a, b) in set.iter().zip(set.iter().skip(1)) {
+            assert!(a.end() < b.start(), "{a:?} and {b:?} are not disjoint");
+        }
+    }
+
+    #[test]
+    fn builds_intervals() {
+        assert_eq!(Interval::closed(3, 5), Interval::half_open(3, 6));
+        assert_eq!(Interval::with_len(3_u64, 3), Interval::half_open(3, 6));
+        assert!(Interval::half_open(4, 4).is_empty());
+        assert!(Interval::closed(4, 4).contains(4));
+        assert_eq!(Interval::closed(2_u64, 4).shift(-2), Interval::closed(0, 2));
+        assert_eq!(
+            Interval::half_open(0, 10).intersection(&Interval::half_open(5, 20)),
+            Interval::half_open(5, 10)
+        );
+    }
+
+    #[test]
+    fn merges_touching_intervals() {
+        let set: IntervalSet<i64> = [
+            Interval::half_open(5, 8),
+            Interval::half_open(-2, 1),
+            Interval::closed(1, 4),
+            Interval::half_open(20, 20),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [Interval::half_open(-2, 8)]
+        );
+        assert_eq!(set.min(), Some(-2));
+    }
+
+    #[test]
+    fn splits_by_interval() {
+        let set: IntervalSet<u64> = [Interval::closed(0, 3), Interval::closed(6, 9)]
+            .into_iter()
+            .collect();
+        let (inside, outside) = set.split(Interval::closed(2, 7));
+
+        assert_eq!(
+            inside.iter().copied().collect::<Vec<_>>(),
+            [Interval::closed(2, 3), Interval::closed(6, 7)]
+        );
+        assert_eq!(
+            outside.iter().copied().collect::<Vec<_>>(),
+            [Interval::closed(0, 1), Interval::closed(8, 9)]
+        );
+    }
+
+    #[test]
+    fn set_operations_match_value_sets() {
+        let mut rng = Rng::new(44);
+
+        for _ in 0..500 {
+            let (a, b) = (random_set(&mut rng), random_set(&mut rng));
+            let (values_a, values_b) = (values(&a), values(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+
+            for set in [&a, &union, &intersection, &difference] {
+                assert_normalized(set);
+            }
+
+            assert_eq!(values(&union), &values_a | &values_b);
+            assert_eq!(values(&intersection), &values_a & &values_b);
+            assert_eq!(values(&difference), &values_a - &values_b);
+
+            let offset = rng.range_i64(0, 8);
+            let shifted: BTreeSet<u64> = values_a.iter().map(|v| v + offset as u64).collect();
+            assert_eq!(values(&a.shift(offset)), shifted);
+        }
+    }
+
+    #[test]
+    fn split_partitions_set() {
+        let mut rng = Rng::new(45);
+
+        for _ in 0..500 {
+            let set = random_set(&mut rng);
+            let start = rng.range(0, DOMAIN);
+            let by = Interval::half_open(start, rng.range(start, DOMAIN));
+            let (inside, outside) = set.split(by);
+
+            assert_normalized(&inside);
+            assert_normalized(&outside);
+            assert!(values(&inside).iter().all(|v| by.contains(*v)));
+            assert!(values(&outside).iter().all(|v| !by.contains(*v)));
+            assert_eq!(inside.union(&outside), set);
+        }
+    }
+}
@@ -3,6 +3,8 @@ pub mod template;
 
 pub use day::*;
 
+pub mod cycle;
+pub mod day10_pipes;
 pub mod day2_cubes;
 pub mod day3_schematic;
 pub mod day4_cards;
@@ -11,4 +13,12 @@ pub mod day6_boats;
 pub mod day7_cards;
 pub mod day8_maps;
 pub mod day9_oasis;
-pub mod day10_pipes;
+pub mod direction;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod render;
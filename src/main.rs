@@ -1,10 +1,16 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, compare, decrypt, download, encrypt, examples, flush, generate, read, scaffold, scale,
+    solve, status, verify,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{commands::solve, terminal},
+        Day,
+    };
 
     pub enum AppArguments {
         Download {
@@ -16,25 +22,92 @@ mod args {
         Scaffold {
             day: Day,
         },
+        Scale {
+            day: Day,
+            real: bool,
+        },
         Solve {
             day: Day,
-            release: bool,
-            time: bool,
-            submit: Option<u8>,
+            options: solve::Options,
         },
         All {
             release: bool,
             time: bool,
+            quiet: bool,
+            all_inputs: bool,
+        },
+        Compare {
+            rev: String,
+            rounds: usize,
+        },
+        Examples {
+            days: Vec<Day>,
+            release: bool,
+        },
+        Decrypt,
+        Encrypt,
+        Flush,
+        Generate {
+            day: Day,
+            size: usize,
+            seed: u64,
+        },
+        Status,
+        Verify {
+            days: Vec<Day>,
+            release: bool,
+            all_inputs: bool,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        // offline mode is passed to child commands (e.g. `--submit` in solution bins) through the environment.
+        if args.contains("--offline") {
+            std::env::set_var("AOC_OFFLINE", "1");
+        }
+
+        // solution bins write into a pipe when run by `all` or `examples`, so they inherit the styling decided here.
+        let plain = args.contains("--plain") || terminal::is_plain();
+        std::env::set_var(terminal::ENV_VAR, if plain { "1" } else { "0" });
+
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
+            // `cargo time --compare <rev>` expands to `all --release --time --compare <rev>`.
+            Some("all") => match args.opt_value_from_str("--compare")? {
+                Some(rev) => {
+                    // comparisons always benchmark release builds, so the flags added by the alias are implied.
+                    args.contains("--release");
+                    args.contains("--time");
+                    AppArguments::Compare {
+                        rev,
+                        rounds: args.opt_value_from_str("--rounds")?.unwrap_or(5),
+                    }
+                }
+                None => AppArguments::All {
+                    release: args.contains("--release"),
+                    time: args.contains("--time"),
+                    quiet: args.contains("--quiet"),
+                    all_inputs: args.contains("--all-inputs"),
+                },
+            },
+            Some("examples") => AppArguments::Examples {
                 release: args.contains("--release"),
-                time: args.contains("--time"),
+                days: {
+                    let mut days = vec![];
+                    while let Some(day) = args.opt_free_from_str()? {
+                        days.push(day);
+                    }
+                    days
+                },
+            },
+            Some("decrypt") => AppArguments::Decrypt,
+            Some("encrypt") => AppArguments::Encrypt,
+            Some("flush") => AppArguments::Flush,
+            Some("generate") => AppArguments::Generate {
+                day: args.free_from_str()?,
+                size: args.opt_value_from_str("--size")?.unwrap_or(1000),
+                seed: args.opt_value_from_str("--seed")?.unwrap_or(1),
             },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
@@ -45,11 +118,36 @@ mod args {
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
             },
+            Some("scale") => AppArguments::Scale {
+                day: args.free_from_str()?,
+                real: args.contains("--real"),
+            },
+            Some("status") => AppArguments::Status,
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
+                options: solve::Options {
+                    release: args.contains("--release"),
+                    part: args.opt_value_from_str("--part")?,
+                    submit_part: args.opt_value_from_str("--submit")?,
+                    time: args.contains("--time"),
+                    profile: args.contains("--profile"),
+                    visualise: args.contains("--visualise"),
+                    record: args.contains("--record"),
+                    verify: args.contains("--verify"),
+                    all_inputs: args.contains("--all-inputs"),
+                    quiet: args.contains("--quiet"),
+                },
+            },
+            Some("verify") => AppArguments::Verify {
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                all_inputs: args.contains("--all-inputs"),
+                days: {
+                    let mut days = vec![];
+                    while let Some(day) = args.opt_free_from_str()? {
+                        days.push(day);
+                    }
+                    days
+                },
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +175,29 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
+            AppArguments::All {
+                release,
+                time,
+                quiet,
+                all_inputs,
+            } => all::handle(release, time, quiet, all_inputs),
+            AppArguments::Compare { rev, rounds } => compare::handle(&rev, rounds),
             AppArguments::Download { day } => download::handle(day),
+            AppArguments::Examples { days, release } => examples::handle(days, release),
+            AppArguments::Decrypt => decrypt::handle(),
+            AppArguments::Encrypt => encrypt::handle(),
+            AppArguments::Flush => flush::handle(),
+            AppArguments::Generate { day, size, seed } => generate::handle(day, size, seed),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
+            AppArguments::Scale { day, real } => scale::handle(day, real),
+            AppArguments::Status => status::handle(),
+            AppArguments::Solve { day, options } => solve::handle(day, options),
+            AppArguments::Verify {
+                days,
                 release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+                all_inputs,
+            } => verify::handle(days, release, all_inputs),
         },
     };
 }
@@ -0,0 +1,153 @@
+//! Module for number theory in cycle-based puzzles. Functions return `None` instead of overflowing.
+
+/// Greatest common divisor, `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `lcm(0, n)` is 0. Returns [`None`] if the result does not fit into `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Greatest common divisor of all values, 0 for no values.
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of all values, 1 for no values. Returns [`None`] on overflow.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclidean algorithm. Returns `(g, x, y)` with `a * x + b * y = g`, where `g` is the non-negative
+/// greatest common divisor of `a` and `b`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or [`None`] if `a` and `modulus` are not coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` after the Chinese remainder theorem.
+/// Moduli do not need to be coprime. Returns the solution as `(residue, modulus)`, i.e. all `x` in
+/// `residue + k * modulus` solve the system, with `residue` in `0..modulus`.
+///
+/// Returns [`None`] if the congruences contradict each other, a modulus is not positive, or the combined modulus
+/// does not fit into `i64`. An empty system is solved by every number, `(0, 1)`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            if m2 <= 0 {
+                return None;
+            }
+
+            // x = r1 + m1 * k, so m1 * k ≡ r2 - r1 (mod m2).
+            let (g, inverse, _) = extended_gcd(m1, m2);
+            let difference = r2.rem_euclid(m2) - r1;
+
+            if difference % g != 0 {
+                return None;
+            }
+
+            let m2_reduced = i128::from(m2 / g);
+            let k = (i128::from(difference / g) * i128::from(inverse)).rem_euclid(m2_reduced);
+            let modulus = i128::from(m1) * m2_reduced;
+            let residue = (i128::from(r1) + i128::from(m1) * k).rem_euclid(modulus);
+
+            Some((i64::try_from(residue).ok()?, i64::try_from(modulus).ok()?))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd_all([12, 18, 8]), 2);
+        assert_eq!(gcd_all([]), 0);
+
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn detects_lcm_overflow() {
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([4_294_967_291, 4_294_967_279, 3]), None);
+        assert_eq!(lcm_all([1 << 62, 1 << 63]), Some(1 << 63));
+    }
+
+    #[test]
+    fn solves_bezout_identity() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (35, 64)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert!(g >= 0);
+            assert_eq!(a * x + b * y, g);
+            assert_eq!(g.unsigned_abs(), gcd(a.unsigned_abs(), b.unsigned_abs()));
+        }
+    }
+
+    #[test]
+    fn computes_modular_inverses() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(1, 0), None);
+    }
+
+    #[test]
+    fn solves_coprime_systems() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 4), (0, 3)]), Some((3, 12)));
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn solves_non_coprime_systems() {
+        // the ghosts of the day 8 example: at Z every 2 and every 3 steps.
+        assert_eq!(crt([(0, 2), (0, 3)]), Some((0, 6)));
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 0)]), None);
+    }
+
+    #[test]
+    fn detects_crt_overflow() {
+        assert_eq!(crt([(1, 4_294_967_311), (2, 4_294_967_357)]), None);
+    }
+}
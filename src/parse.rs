@@ -0,0 +1,396 @@
+//! Module with helpers for parsing puzzle inputs.
+//!
+//! Inputs are read as numbered [`Line`]s. Every value taken from a line still knows where in the line it starts, so
+//! errors can point to the line and column of the offending text. Formatting a [`ParseError`] with `{:#}` renders the
+//! input line with a caret under that text.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// the text that could not be parsed.
+    pub found: String,
+    /// a description of what was expected instead.
+    pub expected: String,
+    /// the whole input line the error was found in.
+    pub source: String,
+}
+
+impl ParseError {
+    /// Moves an error from parsing `part` on its own to where `part` is located in the input.
+    pub fn within(mut self, part: Line) -> Self {
+        if self.line == 1 {
+            self.column += part.column() - 1;
+            self.source = part.line.into();
+        }
+        self.line += part.number - 1;
+        self
+    }
+}
+
+/// The alternate format `{:#}` adds the input line with a caret under the offending text:
+///
+/// ```text
+/// line 2, column 9: expected an unsigned integer, found "x"
+///   |
+/// 2 | Card 1: x 48 83 | 83 86
+///   |         ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found \"{}\"",
+            self.line, self.column, self.expected, self.found
+        )?;
+
+        if f.alternate() {
+            let number = self.line.to_string();
+            let gutter = " ".repeat(number.len());
+            let indent = " ".repeat(self.column - 1);
+            let carets = "^".repeat(self.found.chars().count().max(1));
+
+            write!(f, "\n{gutter} |\n{number} | {}", self.source)?;
+            write!(f, "\n{gutter} | {indent}{carets}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A part of an input line, together with the line it was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    number: usize,
+    line: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Creates a line with a 1-based line number.
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self {
+            number,
+            line: text,
+            text,
+        }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The 1-based column this part starts at.
+    pub fn column(&self) -> usize {
+        let offset = self.text.as_ptr() as usize - self.line.as_ptr() as usize;
+        self.line[..offset].chars().count() + 1
+    }
+
+    /// Returns an error located at the start of this part.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.column(),
+            found: self.text.into(),
+            expected: expected.into(),
+            source: self.line.into(),
+        }
+    }
+
+    /// Narrows this part to a subslice of its text.
+    fn slice(&self, text: &'a str) -> Self {
+        Self { text, ..*self }
+    }
+
+    pub fn trim(&self) -> Self {
+        self.slice(self.text.trim())
+    }
+
+    /// Splits at the first occurrence of `delimiter`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(Self, Self), ParseError> {
+        let (left, right) = self
+            .text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(format!("\"{delimiter}\"")))?;
+        Ok((self.slice(left), self.slice(right)))
+    }
+
+    /// Iterates the parts that are separated by `delimiter`.
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text.split(delimiter).map(move |part| line.slice(part))
+    }
+
+    /// Splits a labelled field like `Time: 7 15 30` into its label and its value, both trimmed.
+    pub fn split_field(&self) -> Result<(Self, Self), ParseError> {
+        let (label, value) = self
+            .split_once(":")
+            .map_err(|_| self.error("a field like \"<label>: <value>\""))?;
+        Ok((label.trim(), value.trim()))
+    }
+
+    /// Returns the trimmed value of a field with the given label.
+    pub fn field(&self, label: &str) -> Result<Self, ParseError> {
+        let (found, value) = self
+            .split_field()
+            .map_err(|_| self.error(format!("a field \"{label}: <value>\"")))?;
+
+        if found.text != label {
+            return Err(found.error(format!("label \"{label}\"")));
+        }
+
+        Ok(value)
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(text) => Ok(self.slice(text)),
+            None => Err(self.error(format!("\"{prefix}\""))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(text) => Ok(self.slice(text)),
+            None => Err(self
+                .slice(&self.text[self.text.len()..])
+                .error(format!("\"{suffix}\""))),
+        }
+    }
+
+    /// Iterates the characters together with the part that holds each of them.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Line<'a>)> + 'a {
+        let line = *self;
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (c, line.slice(&line.text[i..i + c.len_utf8()])))
+    }
+
+    /// Iterates the parts that are separated by whitespace.
+    pub fn tokens(&self) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text
+            .split_ascii_whitespace()
+            .map(move |token| line.slice(token))
+    }
+
+    /// Parses the whole trimmed text with [`str::parse`].
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        let trimmed = self.trim();
+        trimmed.text.parse().map_err(|_| trimmed.error(expected))
+    }
+
+    /// Parses the text with a [`FromStr`] impl that reports a [`ParseError`], locating that error in this line.
+    pub fn parse_into<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|error: ParseError| error.within(*self))
+    }
+
+    /// Parses all whitespace separated tokens as unsigned integers.
+    pub fn unsigned(&self) -> Result<Vec<u64>, ParseError> {
+        self.tokens()
+            .map(|token| token.parse("an unsigned integer"))
+            .collect()
+    }
+
+    /// Parses all whitespace separated tokens as signed integers.
+    pub fn signed(&self) -> Result<Vec<i64>, ParseError> {
+        self.tokens()
+            .map(|token| token.parse("an integer"))
+            .collect()
+    }
+}
+
+/// Iterates the lines of an input, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// Iterates paragraphs of an input, i.e. groups of lines that are separated by blank lines.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = lines(input).peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+
+        let mut paragraph = vec![];
+        while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+            paragraph.push(line);
+        }
+
+        (!paragraph.is_empty()).then_some(paragraph)
+    })
+}
+
+/// A paragraph that starts with a header line like `seed-to-soil map:`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// the header without the trailing colon.
+    pub header: Line<'a>,
+    pub lines: Vec<Line<'a>>,
+}
+
+impl<'a> Block<'a> {
+    /// Splits the header off a non-empty paragraph.
+    pub fn from_paragraph(paragraph: Vec<Line<'a>>) -> Result<Self, ParseError> {
+        let header = paragraph[0].trim();
+        let name = header
+            .text
+            .strip_suffix(':')
+            .ok_or_else(|| header.error("a header like \"<name>:\""))?;
+
+        Ok(Block {
+            header: header.slice(name),
+            lines: paragraph[1..].to_vec(),
+        })
+    }
+}
+
+/// Iterates paragraphs that start with a header line ending in `:`.
+pub fn blocks(input: &str) -> impl Iterator<Item = Result<Block<'_>, ParseError>> {
+    paragraphs(input).map(Block::from_paragraph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_integers() {
+        let line = Line::new(1, " 3  -14 7");
+        assert_eq!(line.signed(), Ok(vec![3, -14, 7]));
+        assert_eq!(
+            line.unsigned(),
+            Err(ParseError {
+                line: 1,
+                column: 5,
+                found: "-14".into(),
+                expected: "an unsigned integer".into(),
+                source: " 3  -14 7".into(),
+            })
+        );
+        assert_eq!(Line::new(1, "").unsigned(), Ok(vec![]));
+    }
+
+    #[test]
+    fn splits_labelled_fields() {
+        let line = Line::new(2, "Time:      7  15   30");
+        let (label, value) = line.split_field().unwrap();
+        assert_eq!(label.text(), "Time");
+        assert_eq!(value.column(), 12);
+        assert_eq!(line.field("Time").unwrap().unsigned(), Ok(vec![7, 15, 30]));
+
+        let error = line.field("Distance").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "Time");
+
+        let error = Line::new(3, "Time 7").field("Time").unwrap_err();
+        assert_eq!(error.expected, "a field \"Time: <value>\"");
+    }
+
+    #[test]
+    fn strips_delimiters() {
+        let line = Line::new(4, "(BBB, CCC)");
+        let inner = line.strip_prefix("(").unwrap().strip_suffix(")").unwrap();
+        assert_eq!((inner.text(), inner.column()), ("BBB, CCC", 2));
+        assert_eq!(inner.strip_suffix(")").unwrap_err().column, 10);
+
+        let parts: Vec<_> = inner.split(", ").map(|part| part.column()).collect();
+        assert_eq!(parts, [2, 7]);
+
+        let (c, part) = line.chars().nth(5).unwrap();
+        assert_eq!((c, part.column()), (' ', 6));
+    }
+
+    #[test]
+    fn reports_columns_in_characters() {
+        let line = Line::new(1, "µs: x");
+        let error = line.field("µs").unwrap().parse::<u8>("a byte").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a byte, found \"x\""
+        );
+    }
+
+    #[test]
+    fn renders_caret_diagnostics() {
+        let line = Line::new(12, "Game 3: 8 green, 6 purple");
+        let color = line.split_once(", ").unwrap().1.tokens().nth(1).unwrap();
+
+        assert_eq!(
+            format!("{:#}", color.error("a color")),
+            [
+                "line 12, column 20: expected a color, found \"purple\"",
+                "   |",
+                "12 | Game 3: 8 green, 6 purple",
+                "   |                    ^^^^^^",
+            ]
+            .join("\n")
+        );
+
+        let missing = Line::new(1, "abc").strip_suffix(")").unwrap_err();
+        assert!(format!("{missing:#}").ends_with("\n1 | abc\n  |    ^"));
+    }
+
+    #[test]
+    fn locates_errors_of_nested_parsers() {
+        #[derive(Debug)]
+        struct Pair(u64);
+
+        impl FromStr for Pair {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (_, right) = Line::new(1, s).split_once("-")?;
+                Ok(Pair(right.parse("a number")?))
+            }
+        }
+
+        let line = Line::new(7, "pairs: 1-2 3-x");
+        let pair = line.tokens().nth(1).unwrap();
+        assert_eq!(pair.parse_into::<Pair>().unwrap().0, 2);
+
+        let error = line
+            .tokens()
+            .nth(2)
+            .unwrap()
+            .parse_into::<Pair>()
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (7, 14));
+        assert_eq!(error.source, "pairs: 1-2 3-x");
+    }
+
+    #[test]
+    fn iterates_paragraphs_and_blocks() {
+        let input = "seeds: 1 2\n\n\na map:\n1 2 3\n4 5 6\n\nb map:\n7 8 9\n";
+
+        let paragraphs: Vec<Vec<Line>> = paragraphs(input).collect();
+        assert_eq!(paragraphs.len(), 3);
+        assert_eq!(paragraphs[1][2].number(), 6);
+
+        let blocks: Vec<_> = blocks(input).collect();
+        assert_eq!(blocks[0].as_ref().unwrap_err().line, 1);
+        let block = blocks[1].as_ref().unwrap();
+        assert_eq!(block.header.text(), "a map");
+        assert_eq!(block.lines.len(), 2);
+        assert_eq!(blocks[2].as_ref().unwrap().lines[0].number(), 9);
+    }
+}
@@ -0,0 +1,236 @@
+//! Module for drawing grids on the terminal.
+//!
+//! Every cell is drawn as a single [`Glyph`], optionally in a color. [`Highlight`]s color sets of positions, later
+//! highlights win over earlier ones. A legend below the grid explains highlights and selected glyphs. Plain output
+//! (see [`terminal::is_plain`]) has no colors, so highlights with a marker draw that marker instead.
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::{
+    grid::{Grid, Position},
+    template::{
+        terminal::{self, Style},
+        ANSI_RESET,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub symbol: char,
+    pub color: Option<Style>,
+}
+
+impl Glyph {
+    pub const fn new(symbol: char) -> Self {
+        Self {
+            symbol,
+            color: None,
+        }
+    }
+
+    pub const fn colored(symbol: char, color: Style) -> Self {
+        Self {
+            symbol,
+            color: Some(color),
+        }
+    }
+}
+
+impl From<char> for Glyph {
+    fn from(symbol: char) -> Self {
+        Self::new(symbol)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Highlight {
+    label: String,
+    color: Style,
+    marker: Option<char>,
+    positions: HashSet<Position>,
+}
+
+impl Highlight {
+    pub fn new(
+        label: impl Into<String>,
+        color: Style,
+        positions: impl IntoIterator<Item = Position>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            color,
+            marker: None,
+            positions: positions.into_iter().collect(),
+        }
+    }
+
+    /// Draws `marker` instead of the glyphs of highlighted cells in plain output.
+    pub fn with_marker(mut self, marker: char) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    /// The glyph that stands for this highlight in the legend, [`None`] if it is invisible.
+    fn key(&self, plain: bool) -> Option<Glyph> {
+        match (plain, self.marker) {
+            (false, _) => Some(Glyph::colored('█', self.color)),
+            (true, marker) => marker.map(Glyph::new),
+        }
+    }
+}
+
+/// A grid together with how to draw it. Draw it through its `Display` impl.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Box<dyn Fn(&T) -> Glyph + 'a>,
+    highlights: Vec<Highlight>,
+    legend: Vec<(Glyph, String)>,
+    plain: bool,
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Draws every cell as the glyph `glyph` returns for it.
+    pub fn new(grid: &'a Grid<T>, glyph: impl Fn(&T) -> Glyph + 'a) -> Self {
+        Self {
+            grid,
+            glyph: Box::new(glyph),
+            highlights: vec![],
+            legend: vec![],
+            plain: terminal::is_plain(),
+        }
+    }
+
+    pub fn highlight(mut self, highlight: Highlight) -> Self {
+        self.highlights.push(highlight);
+        self
+    }
+
+    /// Adds a legend entry that explains a glyph. Highlights are listed in the legend without adding entries.
+    pub fn legend(mut self, glyph: impl Into<Glyph>, label: impl Into<String>) -> Self {
+        self.legend.push((glyph.into(), label.into()));
+        self
+    }
+
+    /// Overrides whether to draw without colors, which is [`terminal::is_plain`] by default.
+    pub fn plain(mut self, plain: bool) -> Self {
+        self.plain = plain;
+        self
+    }
+
+    fn glyph_at(&self, position: Position, cell: &T) -> Glyph {
+        let glyph = (self.glyph)(cell);
+
+        match self
+            .highlights
+            .iter()
+            .rev()
+            .find(|highlight| highlight.positions.contains(&position))
+        {
+            Some(highlight) if self.plain => highlight.marker.map_or(glyph, Glyph::new),
+            Some(highlight) => Glyph::colored(glyph.symbol, highlight.color),
+            None => glyph,
+        }
+    }
+
+    fn write_glyph(&self, f: &mut fmt::Formatter<'_>, glyph: Glyph) -> fmt::Result {
+        match glyph.color {
+            Some(color) if !self.plain => {
+                write!(f, "{}{}{}", color.code(), glyph.symbol, ANSI_RESET.code())
+            }
+            _ => write!(f, "{}", glyph.symbol),
+        }
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, cell) in self.grid.iter() {
+            self.write_glyph(f, self.glyph_at(position, cell))?;
+            if position.0 + 1 == self.grid.width() {
+                writeln!(f)?;
+            }
+        }
+
+        let highlights = self
+            .highlights
+            .iter()
+            .filter_map(|highlight| Some((highlight.key(self.plain)?, &highlight.label)));
+        let entries: Vec<(Glyph, &String)> = highlights
+            .chain(self.legend.iter().map(|(glyph, label)| (*glyph, label)))
+            .collect();
+
+        if !entries.is_empty() {
+            writeln!(f)?;
+        }
+
+        for (glyph, label) in entries {
+            self.write_glyph(f, glyph)?;
+            writeln!(f, " {label}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::{ANSI_GREEN, ANSI_RED};
+
+    fn example() -> Grid<u8> {
+        Grid::parse("012\n345\n", |c| c.to_digit(10).unwrap() as u8).unwrap()
+    }
+
+    fn glyph(value: &u8) -> Glyph {
+        if value.is_multiple_of(2) {
+            Glyph::new('.')
+        } else {
+            Glyph::colored('#', ANSI_GREEN)
+        }
+    }
+
+    #[test]
+    fn draws_glyphs_with_colors() {
+        let grid = example();
+
+        assert_eq!(
+            Render::new(&grid, glyph).plain(true).to_string(),
+            ".#.\n#.#\n"
+        );
+        assert_eq!(
+            Render::new(&grid, glyph).plain(false).to_string(),
+            ".\x1b[32m#\x1b[0m.\n\x1b[32m#\x1b[0m.\x1b[32m#\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn draws_highlights_and_legend() {
+        let grid = example();
+        let render = |plain| {
+            Render::new(&grid, glyph)
+                .highlight(Highlight::new("first", ANSI_RED, [(0, 0), (1, 0)]))
+                .highlight(Highlight::new("second", ANSI_GREEN, [(1, 0), (2, 1)]).with_marker('x'))
+                .legend('.', "even")
+                .plain(plain)
+                .to_string()
+        };
+
+        assert_eq!(render(true), ".x.\n#.x\n\nx second\n. even\n");
+        assert_eq!(
+            render(false),
+            [
+                "\x1b[31m.\x1b[0m\x1b[32m#\x1b[0m.",
+                "\x1b[32m#\x1b[0m.\x1b[32m#\x1b[0m",
+                "",
+                "\x1b[31m█\x1b[0m first",
+                "\x1b[32m█\x1b[0m second",
+                ". even",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Module that records verified answers together with a checksum of the input they were computed for.
+//!
+//! Answers live in `data/answers/<day>.txt`, one line per part: `<part> <input checksum> <answer>`.
+//! Answers for alternate input profiles live in `data/answers/<profile>/<day>.txt`.
+
+use std::{fmt::Display, fs, io};
+
+use crate::template::input_profiles;
+use crate::Day;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedAnswer {
+    pub part: u8,
+    pub checksum: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    WrongAnswer(String),
+    /// the input was changed after the answer was recorded, so the answers can not be compared.
+    InputChanged,
+    NotRecorded,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Correct => write!(f, "correct"),
+            Verification::WrongAnswer(expected) => write!(f, "wrong answer, expected {expected}"),
+            Verification::InputChanged => write!(f, "input changed"),
+            Verification::NotRecorded => write!(f, "not recorded"),
+        }
+    }
+}
+
+#[must_use]
+pub fn get_answers_dir(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("data/answers/{profile}"),
+        None => "data/answers".into(),
+    }
+}
+
+#[must_use]
+pub fn get_profile_answers_path(day: Day, profile: Option<&str>) -> String {
+    format!("{}/{day}.txt", get_answers_dir(profile))
+}
+
+/// Path of the answers of a day for the current input profile.
+#[must_use]
+pub fn get_answers_path(day: Day) -> String {
+    get_profile_answers_path(day, input_profiles::current().as_deref())
+}
+
+pub fn read(day: Day) -> Vec<RecordedAnswer> {
+    fs::read_to_string(get_answers_path(day))
+        .map(|s| parse_answers(&s))
+        .unwrap_or_default()
+}
+
+/// Records an answer for a part, replacing a previously recorded answer.
+pub fn record(day: Day, part: u8, answer: &str, checksum: &str) -> Result<(), io::Error> {
+    let mut answers = read(day);
+    answers.retain(|a| a.part != part);
+    answers.push(RecordedAnswer {
+        part,
+        checksum: checksum.into(),
+        answer: answer.into(),
+    });
+    answers.sort_by_key(|a| a.part);
+
+    fs::create_dir_all(get_answers_dir(input_profiles::current().as_deref()))?;
+    fs::write(get_answers_path(day), format_answers(&answers))
+}
+
+#[must_use]
+pub fn verify(day: Day, part: u8, answer: &str, checksum: &str) -> Verification {
+    check(&read(day), part, answer, checksum)
+}
+
+fn check(answers: &[RecordedAnswer], part: u8, answer: &str, checksum: &str) -> Verification {
+    let Some(recorded) = answers.iter().find(|a| a.part == part) else {
+        return Verification::NotRecorded;
+    };
+
+    if recorded.checksum != checksum {
+        Verification::InputChanged
+    } else if recorded.answer == answer {
+        Verification::Correct
+    } else {
+        Verification::WrongAnswer(recorded.answer.clone())
+    }
+}
+
+fn parse_answers(s: &str) -> Vec<RecordedAnswer> {
+    s.lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ' ');
+            Some(RecordedAnswer {
+                part: parts.next()?.parse().ok()?,
+                checksum: parts.next()?.into(),
+                answer: parts.next()?.into(),
+            })
+        })
+        .collect()
+}
+
+fn format_answers(answers: &[RecordedAnswer]) -> String {
+    answers
+        .iter()
+        .map(|a| format!("{} {} {}\n", a.part, a.checksum, a.answer))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, format_answers, parse_answers, RecordedAnswer, Verification};
+
+    fn get_mock_answers() -> Vec<RecordedAnswer> {
+        vec![
+            RecordedAnswer {
+                part: 1,
+                checksum: "abc".into(),
+                answer: "142".into(),
+            },
+            RecordedAnswer {
+                part: 2,
+                checksum: "abc".into(),
+                answer: "HELLO WORLD".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(check(&answers, 1, "142", "abc"), Verification::Correct);
+        assert_eq!(
+            check(&answers, 1, "143", "abc"),
+            Verification::WrongAnswer("142".into())
+        );
+        assert_eq!(check(&answers, 1, "143", "def"), Verification::InputChanged);
+        assert_eq!(
+            check(&answers[..1], 2, "1", "abc"),
+            Verification::NotRecorded
+        );
+    }
+}
@@ -1,9 +1,18 @@
 /// Wrapper module around the "aoc-cli" command-line.
+///
+/// Every downloaded input and puzzle is also stored in a local cache directory (`AOC_CACHE_DIR`, defaults to `.aoc_cache`).
+/// In offline mode (`AOC_OFFLINE=1` or the `--offline` flag), or when aoc-cli is not installed, downloads are served
+/// from that cache and submissions are queued until they are sent with `cargo flush`.
 use std::{
+    env,
     fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
     process::{Command, Output, Stdio},
 };
 
+use crate::template::{ANSI_BOLD, ANSI_RESET};
 use crate::Day;
 
 #[derive(Debug)]
@@ -12,6 +21,9 @@ pub enum AocCommandError {
     CommandNotCallable,
     BadExitStatus(Output),
     IoError,
+    CacheMiss(String),
+    MalformedSubmission,
+    Offline,
 }
 
 impl Display for AocCommandError {
@@ -23,6 +35,21 @@ impl Display for AocCommandError {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
             AocCommandError::IoError => write!(f, "could not write output files to file system."),
+            AocCommandError::CacheMiss(path) => {
+                write!(f, "\"{path}\" is not in the local cache.")
+            }
+            AocCommandError::MalformedSubmission => {
+                write!(
+                    f,
+                    "queued submission is not of the form \"<day> <part> <answer>\"."
+                )
+            }
+            AocCommandError::Offline => {
+                write!(
+                    f,
+                    "aoc-cli can not be used in offline mode or when it is not installed."
+                )
+            }
         }
     }
 }
@@ -35,26 +62,79 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
+/// Returns `true` if commands should use the local cache instead of calling aoc-cli,
+/// either because offline mode is enabled or because aoc-cli is not installed.
+pub fn use_cache() -> bool {
+    if is_offline() {
+        return true;
+    }
+
+    if check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable, using the local cache instead. Try running \"cargo install aoc-cli\" to install it.");
+        return true;
+    }
+
+    false
+}
+
+pub fn is_offline() -> bool {
+    env::var("AOC_OFFLINE").is_ok_and(|x| !x.is_empty() && x != "0" && x != "false")
+}
+
+pub fn read(day: Day) -> Result<(), AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
 
+    if use_cache() {
+        // the local copy can be newer than the cache, e.g. after solving a part online.
+        if !fs::metadata(&puzzle_path).is_ok_and(|m| m.len() > 0) {
+            restore_from_cache(&puzzle_path, &get_cached_puzzle_path(day))?;
+        }
+        return render_puzzle(&puzzle_path);
+    }
+
     let args = build_args(
         "read",
         &[
             "--description-only".into(),
             "--puzzle-file".into(),
-            puzzle_path,
+            puzzle_path.clone(),
         ],
         day,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(&args)?;
+    store_in_cache(&puzzle_path, &get_cached_puzzle_path(day))
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
+pub fn download(day: Day) -> Result<(), AocCommandError> {
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
 
+    if use_cache() {
+        // restoring the plain input next to an encrypted one would leave the decrypted input on disk.
+        let encrypted_path = format!("{input_path}.enc");
+        let is_encrypted = Path::new(&encrypted_path).exists();
+        let restored_input = if is_encrypted {
+            None
+        } else {
+            Some(restore_from_cache(
+                &input_path,
+                &get_cached_input_path(day),
+            )?)
+        };
+        let restored_puzzle = restore_from_cache(&puzzle_path, &get_cached_puzzle_path(day))?;
+
+        println!("---");
+        match restored_input {
+            Some(restored) => print_restore("input", &input_path, restored),
+            None => println!(
+                "🎄 Kept encrypted input \"{encrypted_path}\", the plain input is not restored."
+            ),
+        }
+        print_restore("puzzle", &puzzle_path, restored_puzzle);
+        return Ok(());
+    }
+
     let args = build_args(
         "download",
         &[
@@ -67,19 +147,152 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
+    call_aoc_cli(&args)?;
+    store_in_cache(&input_path, &get_cached_input_path(day))?;
+    store_in_cache(&puzzle_path, &get_cached_puzzle_path(day))?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+    Ok(())
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Submits an answer, or queues it for `cargo flush` in offline mode.
+pub fn submit(day: Day, part: u8, result: &str) -> Result<(), AocCommandError> {
+    if is_offline() {
+        queue_submission(day, part, result)?;
+        println!("🎄 Queued answer for day {day}, part {part}. Run `cargo flush` when online to submit it.");
+        return Ok(());
+    }
+
+    check()?;
+    call_submit(day, part, result)
+}
+
+/// Submits all queued answers. Answers that fail to submit stay in the queue.
+pub fn flush_submissions() -> Result<usize, AocCommandError> {
+    if use_cache() {
+        return Err(AocCommandError::Offline);
+    }
+
+    let queue_path = get_cache_dir().join("submissions.txt");
+    let Ok(queue) = fs::read_to_string(&queue_path) else {
+        return Ok(0);
+    };
+
+    let mut submitted = 0;
+    let mut remaining = vec![];
+
+    for line in queue.lines().filter(|l| !l.trim().is_empty()) {
+        let parsed = line.splitn(3, ' ').collect::<Vec<_>>();
+        let result = match parsed.as_slice() {
+            [day, part, answer] => match (day.parse::<Day>(), part.parse::<u8>()) {
+                (Ok(day), Ok(part)) => {
+                    println!("Submitting day {day}, part {part}: {answer}");
+                    call_submit(day, part, answer)
+                }
+                _ => Err(AocCommandError::MalformedSubmission),
+            },
+            _ => Err(AocCommandError::MalformedSubmission),
+        };
+
+        match result {
+            Ok(()) => submitted += 1,
+            Err(e) => {
+                eprintln!("Failed to submit \"{line}\": {e}");
+                remaining.push(line);
+            }
+        }
+    }
+
+    if remaining.is_empty() {
+        fs::remove_file(&queue_path).map_err(|_| AocCommandError::IoError)?;
+    } else {
+        fs::write(&queue_path, remaining.join("\n") + "\n")
+            .map_err(|_| AocCommandError::IoError)?;
+    }
+
+    Ok(submitted)
+}
+
+fn call_submit(day: Day, part: u8, result: &str) -> Result<(), AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+    call_aoc_cli(&args)?;
+    Ok(())
+}
+
+fn queue_submission(day: Day, part: u8, result: &str) -> Result<(), AocCommandError> {
+    let cache_dir = get_cache_dir();
+    fs::create_dir_all(&cache_dir).map_err(|_| AocCommandError::IoError)?;
+
+    let mut queue = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(cache_dir.join("submissions.txt"))
+        .map_err(|_| AocCommandError::IoError)?;
+
+    writeln!(queue, "{day} {part} {result}").map_err(|_| AocCommandError::IoError)
+}
+
+/// Prints a puzzle description, with markdown headings in bold.
+fn render_puzzle(puzzle_path: &str) -> Result<(), AocCommandError> {
+    let puzzle = fs::read_to_string(puzzle_path).map_err(|_| AocCommandError::IoError)?;
+
+    for line in puzzle.lines() {
+        match line.strip_prefix("## ") {
+            Some(heading) => println!("{ANSI_BOLD}{heading}{ANSI_RESET}"),
+            None => println!("{line}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn store_in_cache(path: &str, cached_path: &Path) -> Result<(), AocCommandError> {
+    if let Some(parent) = cached_path.parent() {
+        fs::create_dir_all(parent).map_err(|_| AocCommandError::IoError)?;
+    }
+    fs::copy(path, cached_path).map_err(|_| AocCommandError::IoError)?;
+    Ok(())
+}
+
+fn print_restore(kind: &str, path: &str, restored: bool) {
+    if restored {
+        println!("🎄 Restored {kind} \"{path}\" from the local cache.");
+    } else {
+        println!("🎄 Kept existing {kind} \"{path}\", it is not in the local cache.");
+    }
+}
+
+/// Copies a cached file to `path`. Returns `false` if it is not cached but `path` already has content.
+fn restore_from_cache(path: &str, cached_path: &Path) -> Result<bool, AocCommandError> {
+    if !cached_path.exists() {
+        // fall back to a file that already exists in the repository.
+        if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(false);
+        }
+        return Err(AocCommandError::CacheMiss(
+            cached_path.display().to_string(),
+        ));
+    }
+    fs::copy(cached_path, path).map_err(|_| AocCommandError::IoError)?;
+    Ok(true)
+}
+
+fn get_cache_dir() -> PathBuf {
+    let cache_dir = env::var("AOC_CACHE_DIR").unwrap_or_else(|_| ".aoc_cache".into());
+    let year = get_year().map_or_else(|| "default".into(), |year| year.to_string());
+    PathBuf::from(cache_dir).join(year)
+}
+
+fn get_cached_input_path(day: Day) -> PathBuf {
+    get_cache_dir().join(format!("{day}.txt"))
+}
+
+fn get_cached_puzzle_path(day: Day) -> PathBuf {
+    get_cache_dir().join(format!("{day}.md"))
 }
 
 fn get_input_path(day: Day) -> String {
@@ -0,0 +1,140 @@
+//! Module that keeps a history of benchmark results in `data/benchmark_history.txt`.
+//! Every entry stores the checksum of the input it was measured on, entries for different inputs are never compared.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::Day;
+
+static PATH: &str = "data/benchmark_history.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub day: Day,
+    pub checksum: String,
+    pub total_nanos: f64,
+}
+
+impl Entry {
+    #[must_use]
+    pub fn now(day: Day, checksum: String, total_nanos: f64) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            day,
+            checksum,
+            total_nanos,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Comparison {
+    /// relative change of the total time compared to the latest run on the same input.
+    Change(f64),
+    /// there are earlier runs for this day, but none on the same input.
+    InputChanged,
+    FirstRun,
+}
+
+pub fn read() -> Vec<Entry> {
+    fs::read_to_string(PATH)
+        .map(|s| parse_history(&s))
+        .unwrap_or_default()
+}
+
+pub fn append(entries: &[Entry]) -> Result<(), io::Error> {
+    let mut file = OpenOptions::new().create(true).append(true).open(PATH)?;
+
+    for entry in entries {
+        writeln!(
+            file,
+            "{} {} {} {}",
+            entry.timestamp, entry.day, entry.checksum, entry.total_nanos
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Compares an entry to the latest entry in `history` for the same day and input.
+#[must_use]
+pub fn compare(history: &[Entry], entry: &Entry) -> Comparison {
+    let same_day = history.iter().filter(|e| e.day == entry.day);
+
+    match same_day.clone().rfind(|e| e.checksum == entry.checksum) {
+        Some(previous) if previous.total_nanos > 0.0 => {
+            Comparison::Change(entry.total_nanos / previous.total_nanos - 1.0)
+        }
+        Some(_) => Comparison::FirstRun,
+        None if same_day.count() > 0 => Comparison::InputChanged,
+        None => Comparison::FirstRun,
+    }
+}
+
+fn parse_history(s: &str) -> Vec<Entry> {
+    s.lines()
+        .filter_map(|line| {
+            let [timestamp, day, checksum, total_nanos] = line
+                .split_ascii_whitespace()
+                .collect::<Vec<_>>()
+                .try_into()
+                .ok()?;
+
+            Some(Entry {
+                timestamp: timestamp.parse().ok()?,
+                day: day.parse().ok()?,
+                checksum: checksum.into(),
+                total_nanos: total_nanos.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, parse_history, Comparison, Entry};
+    use crate::day;
+
+    fn get_mock_history() -> Vec<Entry> {
+        parse_history("1 01 aaa 100\n2 01 bbb 50\n3 02 ccc 10\ninvalid line\n")
+    }
+
+    #[test]
+    fn parses_history() {
+        let history = get_mock_history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[1].day, day!(1));
+        assert_eq!(history[1].checksum, "bbb");
+    }
+
+    #[test]
+    fn compares_same_input_only() {
+        let history = get_mock_history();
+
+        let entry = Entry {
+            timestamp: 4,
+            day: day!(1),
+            checksum: "aaa".into(),
+            total_nanos: 150.0,
+        };
+        assert_eq!(compare(&history, &entry), Comparison::Change(0.5));
+
+        let entry = Entry {
+            checksum: "ddd".into(),
+            ..entry
+        };
+        assert_eq!(compare(&history, &entry), Comparison::InputChanged);
+
+        let entry = Entry {
+            day: day!(3),
+            ..entry
+        };
+        assert_eq!(compare(&history, &entry), Comparison::FirstRun);
+    }
+}
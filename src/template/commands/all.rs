@@ -1,15 +1,33 @@
-use std::io;
+use std::{io, process};
 
 use crate::template::{
+    benchmark_history::{self, Comparison},
+    checksum, encryption,
     readme_benchmarks::{self, Timings},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 use crate::{all_days, Day};
 
-pub fn handle(is_release: bool, is_timed: bool) {
+pub fn handle(is_release: bool, is_timed: bool, is_quiet: bool, all_inputs: bool) {
+    // timings and the readme benchmarks always refer to a single input.
+    if all_inputs {
+        eprintln!("Cannot run all days for all inputs, `all` only runs the selected input profile. Use `cargo solve <day> --all-inputs` or `cargo verify --all-inputs` instead.");
+        process::exit(1);
+    }
+
+    if is_timed && is_quiet {
+        eprintln!("Cannot collect timings in quiet mode. Remove `--quiet` to time all solutions.");
+        process::exit(1);
+    }
+
     let mut timings: Vec<Timings> = vec![];
 
     all_days().for_each(|day| {
+        if is_quiet {
+            child_commands::run_solution(day, false, is_release, true).unwrap();
+            return;
+        }
+
         if day > 1 {
             println!();
         }
@@ -17,7 +35,7 @@ pub fn handle(is_release: bool, is_timed: bool) {
         println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
         println!("------");
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let output = child_commands::run_solution(day, is_timed, is_release, false).unwrap();
 
         if output.is_empty() {
             println!("Not solved.");
@@ -33,6 +51,8 @@ pub fn handle(is_release: bool, is_timed: bool) {
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
         if is_release {
+            record_history(&timings);
+
             match readme_benchmarks::update(timings, total_millis) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
@@ -43,6 +63,39 @@ pub fn handle(is_release: bool, is_timed: bool) {
     }
 }
 
+/// Compares the timings to the previous run on the same input and appends them to the benchmark history.
+fn record_history(timings: &[Timings]) {
+    let history = benchmark_history::read();
+    let mut entries = vec![];
+
+    println!();
+
+    for timing in timings {
+        // timings are only meaningful in relation to the input they were measured on.
+        let Ok(input) = encryption::read_input(timing.day) else {
+            continue;
+        };
+
+        let entry = benchmark_history::Entry::now(timing.day, checksum(&input), timing.total_nanos);
+
+        match benchmark_history::compare(&history, &entry) {
+            Comparison::Change(change) => {
+                println!("Day {}: {:+.1}% since last run", timing.day, change * 100.0);
+            }
+            Comparison::InputChanged => {
+                println!("Day {}: input changed, not compared", timing.day);
+            }
+            Comparison::FirstRun => {}
+        }
+
+        entries.push(entry);
+    }
+
+    if let Err(e) = benchmark_history::append(&entries) {
+        eprintln!("Failed to update benchmark history: {e}");
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
@@ -63,7 +116,7 @@ pub fn get_path_for_bin(day: Day) -> String {
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
+pub(crate) mod child_commands {
     use super::{get_path_for_bin, Error};
     use crate::Day;
     use std::{
@@ -74,7 +127,12 @@ mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        is_quiet: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
@@ -87,12 +145,17 @@ mod child_commands {
             args.push("--release");
         }
 
+        args.push("--");
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        if is_quiet {
+            args.push("--quiet");
+        }
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
@@ -128,6 +191,7 @@ mod child_commands {
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
         let mut timings = super::Timings {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
@@ -149,7 +213,9 @@ mod child_commands {
                 Some((part, timing_str, nanos))
             })
             .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
+                if part.contains("Parse") {
+                    timings.parse = Some(timing_str.into());
+                } else if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
@@ -171,19 +237,22 @@ mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
+        Some((str_timing, parse_duration(str_timing)?))
+    }
+
+    /// Parses a duration in `Debug` format like `1.5µs` to nanoseconds.
+    pub fn parse_duration(s: &str) -> Option<f64> {
+        match s {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -221,6 +290,23 @@ mod child_commands {
             assert_eq!(res.part_2.unwrap(), "74.13ms");
         }
 
+        #[test]
+        fn test_parse_timing() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (1.5µs @ 10000 samples)".into(),
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "Part 2: 10 (2.5µs @ 99999 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 4074.13_f64);
+            assert_eq!(res.parse.unwrap(), "1.5µs");
+            assert_eq!(res.part_1.unwrap(), "74.13ns");
+            assert_eq!(res.part_2.unwrap(), "2.5µs");
+        }
+
         #[test]
         fn test_patterns_in_input() {
             let res = parse_exec_time(
@@ -236,6 +322,21 @@ mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn test_skipped_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: skipped".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130000_f64);
+            assert!(res.part_1.is_none());
+            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        }
+
         #[test]
         fn test_missing_parts() {
             let res = parse_exec_time(
@@ -247,8 +348,8 @@ mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
         }
     }
 }
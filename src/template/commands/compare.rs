@@ -0,0 +1,226 @@
+use std::{
+    env,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    time::Duration,
+};
+
+use crate::template::{
+    commands::all::{child_commands, get_path_for_bin},
+    stats::{self, welch_t_test},
+    terminal, ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET,
+};
+use crate::{all_days, Day};
+
+#[derive(Debug)]
+pub enum Error {
+    Git(String),
+    Build(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Git(s) => write!(f, "git failed: {s}"),
+            Error::Build(s) => write!(f, "build of {s} failed"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// A detached git worktree in a temporary directory, removed when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(rev: &str) -> Result<Self, Error> {
+        let path = env::temp_dir().join(format!("aoc-compare-{}", process::id()));
+
+        let output = Command::new("git")
+            .args(["worktree", "add", "--detach"])
+            .arg(&path)
+            .arg(rev)
+            .output()?;
+
+        if !output.status.success() {
+            return Err(Error::Git(
+                String::from_utf8_lossy(&output.stderr).trim().into(),
+            ));
+        }
+
+        Ok(Self { path })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = Command::new("git")
+            .args(["worktree", "remove", "--force"])
+            .arg(&self.path)
+            .output();
+    }
+}
+
+/// Timings of one part, one entry in nanoseconds per round.
+#[derive(Debug, Default)]
+struct Samples {
+    baseline: Vec<f64>,
+    current: Vec<f64>,
+}
+
+pub fn handle(rev: &str, rounds: usize) {
+    if let Err(e) = compare(rev, rounds) {
+        eprintln!("Failed to compare with {rev}: {e}");
+        process::exit(1);
+    }
+}
+
+fn compare(rev: &str, rounds: usize) -> Result<(), Error> {
+    let worktree = Worktree::add(rev)?;
+    let baseline_target = worktree.path.join("target");
+    let current_target =
+        PathBuf::from(env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into()));
+
+    println!("{ANSI_BOLD}Building {rev}{ANSI_RESET}");
+    build(&worktree.path, &baseline_target, rev)?;
+    println!("{ANSI_BOLD}Building working tree{ANSI_RESET}");
+    build(Path::new("."), &current_target, "working tree")?;
+
+    let days: Vec<Day> = all_days()
+        .filter(|day| {
+            Path::new(&get_path_for_bin(*day)).exists()
+                && worktree.path.join(get_path_for_bin(*day)).exists()
+        })
+        .collect();
+
+    let mut results: Vec<(Day, [Samples; 2])> = vec![];
+
+    for day in days {
+        let mut samples: [Samples; 2] = Default::default();
+
+        // alternate between both builds, so that noise like thermal throttling affects both equally.
+        for round in 1..=rounds {
+            println!("Day {day}: round {round}/{rounds}");
+
+            let baseline = run_timed(&baseline_target, day)?;
+            let current = run_timed(&current_target, day)?;
+
+            for (part, samples) in samples.iter_mut().enumerate() {
+                if let (Some(b), Some(c)) = (baseline[part], current[part]) {
+                    samples.baseline.push(b);
+                    samples.current.push(c);
+                }
+            }
+
+            // e.g. a missing input, more rounds will not help.
+            if samples.iter().all(|s| s.current.is_empty()) {
+                break;
+            }
+        }
+
+        results.push((day, samples));
+    }
+
+    println!();
+    print_table(rev, &results);
+    Ok(())
+}
+
+fn build(dir: &Path, target_dir: &Path, name: &str) -> Result<(), Error> {
+    let status = Command::new("cargo")
+        .args(["build", "--quiet", "--release", "--bins"])
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Build(name.into()))
+    }
+}
+
+/// Runs a solution bin with `--time` from the current directory, so both builds read the same inputs.
+/// Returns the average time of each part in nanoseconds.
+fn run_timed(target_dir: &Path, day: Day) -> Result<[Option<f64>; 2], Error> {
+    let output = Command::new(target_dir.join("release").join(day.to_string()))
+        .arg("--time")
+        .env(terminal::ENV_VAR, "1")
+        .stderr(Stdio::null())
+        .output()?;
+
+    let lines: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(String::from)
+        .collect();
+
+    let timings = child_commands::parse_exec_time(&lines, day);
+    let parse = |s: Option<String>| s.as_deref().and_then(child_commands::parse_duration);
+
+    Ok([parse(timings.part_1), parse(timings.part_2)])
+}
+
+fn print_table(rev: &str, results: &[(Day, [Samples; 2])]) {
+    let rev_label: String = rev.chars().take(12).collect();
+
+    println!(
+        "{ANSI_BOLD}{:<5}{:<6}{:<14}{:<14}{:<10}Significant{ANSI_RESET}",
+        "Day", "Part", rev_label, "current", "Speedup"
+    );
+
+    for (day, samples) in results {
+        let day_str = day.to_string();
+
+        if samples.iter().all(|s| s.current.is_empty()) {
+            println!("{day_str:<5}{:<6}{ANSI_DIM}no timings{ANSI_RESET}", "-");
+            continue;
+        }
+
+        for (part, samples) in samples.iter().enumerate() {
+            let part = part + 1;
+
+            if samples.current.is_empty() {
+                println!("{day_str:<5}{part:<6}{ANSI_DIM}no timings{ANSI_RESET}");
+                continue;
+            }
+
+            let baseline = stats::mean(&samples.baseline);
+            let current = stats::mean(&samples.current);
+            let speedup = baseline / current;
+
+            let significance = match welch_t_test(&samples.baseline, &samples.current) {
+                Some(test) if test.is_significant() && speedup > 1.0 => {
+                    format!("{ANSI_GREEN}✔ faster{ANSI_RESET}")
+                }
+                Some(test) if test.is_significant() => format!("{ANSI_RED}✔ slower{ANSI_RESET}"),
+                Some(_) => format!("{ANSI_DIM}✖ noise{ANSI_RESET}"),
+                None => format!("{ANSI_DIM}- too few rounds{ANSI_RESET}"),
+            };
+
+            println!(
+                "{day_str:<5}{part:<6}{:<14}{:<14}{:<10}{significance}",
+                format_nanos(baseline),
+                format_nanos(current),
+                format!("{speedup:.2}×"),
+            );
+        }
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
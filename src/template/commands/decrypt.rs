@@ -0,0 +1,15 @@
+use std::process;
+
+use crate::template::encryption;
+
+pub fn handle() {
+    let result = encryption::read_key().and_then(|key| encryption::decrypt_folder(&key));
+
+    match result {
+        Ok(count) => println!("🔓 Decrypted {count} input file(s)."),
+        Err(e) => {
+            eprintln!("failed to decrypt inputs: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::process;
+
+use crate::template::encryption::{self, Error};
+
+pub fn handle() {
+    let key = match encryption::read_key() {
+        Ok(key) => key,
+        Err(Error::MissingKey) => match encryption::generate_key() {
+            Ok(path) => {
+                println!("🔑 Created new input key at \"{}\". Share it with your team outside of the repository.", path.display());
+                encryption::read_key().unwrap_or_else(|e| exit_with(&e))
+            }
+            Err(e) => exit_with(&e),
+        },
+        Err(e) => exit_with(&e),
+    };
+
+    match encryption::encrypt_folder(&key) {
+        Ok(count) => println!("🔒 Encrypted {count} input file(s)."),
+        Err(e) => exit_with(&e),
+    }
+}
+
+fn exit_with(e: &Error) -> ! {
+    eprintln!("failed to encrypt inputs: {e}");
+    process::exit(1);
+}
@@ -0,0 +1,170 @@
+use std::{path::Path, process};
+
+use crate::template::{
+    commands::{all::get_path_for_bin, run_captured},
+    examples, ANSI_BOLD, ANSI_RESET,
+};
+use crate::{all_days, Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail,
+    Panic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExampleResult {
+    file: String,
+    part: u8,
+    outcome: Outcome,
+    duration: String,
+}
+
+pub fn handle(days: Vec<Day>, is_release: bool) {
+    let days = if days.is_empty() {
+        all_days().collect()
+    } else {
+        days
+    };
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    println!(
+        "{ANSI_BOLD}{:<5}{:<14}{:<18}{:<18}{ANSI_RESET}",
+        "Day", "Example", "Part 1", "Part 2"
+    );
+
+    for day in days {
+        // skip days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            continue;
+        }
+
+        // `Day` does not honor width flags, pad its string representation instead.
+        let day_str = day.to_string();
+
+        let Ok(manifest) = examples::read_manifest(day) else {
+            println!("{day_str:<5}{:<14}", "no manifest");
+            continue;
+        };
+
+        if manifest
+            .iter()
+            .all(|e| e.part_1.is_none() && e.part_2.is_none())
+        {
+            println!("{day_str:<5}{:<14}", "no answers");
+            continue;
+        }
+
+        let results = match run_captured(day, is_release, None, &["--examples"]) {
+            Ok(output) => parse_results(&output),
+            Err(e) => {
+                eprintln!("Failed to run examples for day {day}: {e}");
+                vec![]
+            }
+        };
+
+        if results.is_empty() {
+            println!("{day_str:<5}{:<14}", "no results");
+            failed += 1;
+            continue;
+        }
+
+        let mut files: Vec<&str> = vec![];
+        for result in &results {
+            if !files.contains(&result.file.as_str()) {
+                files.push(&result.file);
+            }
+        }
+
+        for file in files {
+            let cell = |part: u8| {
+                results
+                    .iter()
+                    .find(|r| r.file == file && r.part == part)
+                    .map_or_else(|| "-".into(), format_cell)
+            };
+            println!("{day_str:<5}{file:<14}{:<18}{:<18}", cell(1), cell(2));
+        }
+
+        for result in &results {
+            if result.outcome == Outcome::Pass {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+        }
+    }
+
+    println!();
+    println!("{ANSI_BOLD}{passed} passed, {failed} failed.{ANSI_RESET}");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn format_cell(result: &ExampleResult) -> String {
+    match result.outcome {
+        Outcome::Pass => format!("✔ {}", result.duration),
+        Outcome::Fail => format!("✖ {}", result.duration),
+        Outcome::Panic => "✖ panic".into(),
+    }
+}
+
+/// Parse lines like `08-1.txt Part 1: fail, got 3, expected 2 (1.2µs)`.
+fn parse_results(output: &[String]) -> Vec<ExampleResult> {
+    output
+        .iter()
+        .filter_map(|line| {
+            let (file, rest) = line.split_once(" Part ")?;
+            let (part, rest) = rest.split_once(": ")?;
+
+            let outcome = match rest.split([',', ' ']).next()? {
+                "pass" => Outcome::Pass,
+                "fail" => Outcome::Fail,
+                "panic" => Outcome::Panic,
+                _ => return None,
+            };
+
+            let duration = rest
+                .rsplit_once('(')
+                .and_then(|(_, d)| d.strip_suffix(')'))
+                .unwrap_or_default();
+
+            Some(ExampleResult {
+                file: file.into(),
+                part: part.parse().ok()?,
+                outcome,
+                duration: duration.into(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_results, Outcome};
+
+    #[test]
+    fn parses_example_results() {
+        let results = parse_results(&[
+            "08-1.txt Part 1: pass (1.2µs)".into(),
+            "08-2.txt Part 1: fail, got 3 (4), expected 6 (10.0ms)".into(),
+            "08-3.txt Part 2: panic (3.0ns)".into(),
+            "unrelated output".into(),
+        ]);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].file, "08-1.txt");
+        assert_eq!(results[0].part, 1);
+        assert_eq!(results[0].outcome, Outcome::Pass);
+        assert_eq!(results[0].duration, "1.2µs");
+        assert_eq!(results[1].outcome, Outcome::Fail);
+        assert_eq!(results[1].duration, "10.0ms");
+        assert_eq!(results[2].part, 2);
+        assert_eq!(results[2].outcome, Outcome::Panic);
+    }
+}
@@ -0,0 +1,14 @@
+use std::process;
+
+use crate::template::aoc_cli;
+
+pub fn handle() {
+    match aoc_cli::flush_submissions() {
+        Ok(0) => println!("No queued submissions."),
+        Ok(n) => println!("🎄 Submitted {n} queued answer(s)."),
+        Err(e) => {
+            eprintln!("failed to submit queued answers: {e}");
+            process::exit(1);
+        }
+    }
+}
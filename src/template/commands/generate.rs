@@ -0,0 +1,36 @@
+use std::{fs, process};
+
+use crate::generate::{self, Rng};
+use crate::template::input_profiles;
+use crate::Day;
+
+/// Input profile that generated inputs are written to.
+pub const PROFILE: &str = "generated";
+
+pub fn handle(day: Day, size: usize, seed: u64) {
+    let Some(input) = generate::for_day(day, size, &mut Rng::new(seed)) else {
+        let days: Vec<String> = generate::DAYS.iter().map(u8::to_string).collect();
+        eprintln!(
+            "There is no input generator for day {day}. Generators exist for days {}.",
+            days.join(", ")
+        );
+        process::exit(1);
+    };
+
+    let dir = input_profiles::get_inputs_dir(Some(PROFILE));
+    let path = format!("{dir}/{day}.txt");
+
+    let result = fs::create_dir_all(&dir).and_then(|()| fs::write(&path, input));
+
+    match result {
+        Ok(()) => {
+            println!("Generated input of size {size} with seed {seed} at \"{path}\"");
+            println!("---");
+            println!("🎄 Run it with `AOC_INPUT_PROFILE={PROFILE} cargo solve {day}`.");
+        }
+        Err(e) => {
+            eprintln!("Failed to write generated input: {e}");
+            process::exit(1);
+        }
+    }
+}
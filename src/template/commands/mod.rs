@@ -1,5 +1,50 @@
+use std::process::{Command, Stdio};
+
+use crate::template::{input_profiles, terminal};
+use crate::Day;
+
 pub mod all;
+pub mod compare;
+pub mod decrypt;
 pub mod download;
+pub mod encrypt;
+pub mod examples;
+pub mod flush;
+pub mod generate;
 pub mod read;
 pub mod scaffold;
+pub mod scale;
 pub mod solve;
+pub mod status;
+pub mod verify;
+
+/// Run the solution bin for a given day and input profile with extra arguments and capture its output lines.
+fn run_captured(
+    day: Day,
+    is_release: bool,
+    profile: Option<&str>,
+    bin_args: &[&str],
+) -> Result<Vec<String>, std::io::Error> {
+    let day_padded = day.to_string();
+    let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+
+    if is_release {
+        args.push("--release");
+    }
+
+    args.push("--");
+    args.extend_from_slice(bin_args);
+
+    let output = Command::new("cargo")
+        .args(&args)
+        .env(input_profiles::ENV_VAR, profile.unwrap_or_default())
+        // the output is parsed, not shown.
+        .env(terminal::ENV_VAR, "1")
+        .stderr(Stdio::inherit())
+        .output()?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(String::from)
+        .collect())
+}
@@ -1,9 +1,11 @@
 use std::{
     fs::{File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
+use crate::template::encryption;
 use crate::Day;
 
 const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
@@ -34,17 +36,26 @@ mod tests {
 }
 "#;
 
+const MANIFEST_TEMPLATE: &str = r#"# example   part 1   part 2
+DAY_NUMBER.txt      -        -
+"#;
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 pub fn handle(day: Day) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
+    let manifest_path = format!("data/examples/{day}.manifest");
     let module_path = format!("src/bin/{day}.rs");
 
     let mut file = match safe_create_file(&module_path) {
@@ -69,13 +80,21 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    // an empty plain input would shadow an encrypted one.
+    if Path::new(&encryption::get_encrypted_path(day)).exists() {
+        println!(
+            "Using encrypted input file \"{}\"",
+            encryption::get_encrypted_path(day)
+        );
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
@@ -89,6 +108,22 @@ pub fn handle(day: Day) {
         }
     }
 
+    match safe_create_file(&manifest_path).and_then(|mut file| {
+        file.write_all(
+            MANIFEST_TEMPLATE
+                .replace("DAY_NUMBER", &day.to_string())
+                .as_bytes(),
+        )
+    }) {
+        Ok(()) => {
+            println!("Created example manifest \"{}\"", &manifest_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example manifest: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {}` to run your solution.", day);
 }
@@ -1,16 +1,71 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
+use crate::template::{input_profiles, ANSI_BOLD, ANSI_RESET};
 use crate::Day;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Options {
+    pub release: bool,
+    pub time: bool,
+    pub profile: bool,
+    pub visualise: bool,
+    pub record: bool,
+    pub verify: bool,
+    pub all_inputs: bool,
+    pub quiet: bool,
+    pub part: Option<u8>,
+    pub submit_part: Option<u8>,
+}
+
+pub fn handle(day: Day, options: Options) {
+    let Options {
+        release,
+        time,
+        profile,
+        visualise,
+        record,
+        verify,
+        all_inputs,
+        quiet,
+        part,
+        submit_part,
+    } = options;
+
+    if all_inputs && submit_part.is_some() {
+        eprintln!("Cannot submit answers for all inputs. Remove `--all-inputs` to submit the answer for your own input.");
+        process::exit(1);
+    }
+
+    if let Some(part) = part {
+        if part != 1 && part != 2 {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --part 2");
+            process::exit(1);
+        }
+
+        if submit_part.is_some_and(|submit_part| submit_part != part) {
+            eprintln!("Cannot submit a part that is not run. Use the same value for `--part` and `--submit`.");
+            process::exit(1);
+        }
+    }
+
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if quiet {
+        // also silences the build output of cargo.
+        cmd_args.push("--quiet".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
@@ -20,12 +75,69 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push("--time".to_string());
     }
 
+    if profile {
+        cmd_args.push("--profile".to_string());
+    }
+
+    if visualise {
+        cmd_args.push("--visualise".to_string());
+    }
+
+    if record {
+        cmd_args.push("--record".to_string());
+    }
+
+    if verify {
+        cmd_args.push("--verify".to_string());
+    }
+
+    if quiet {
+        cmd_args.push("--quiet".to_string());
+    }
+
+    if !all_inputs {
+        if !run_solution(&cmd_args, input_profiles::current().as_deref()) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let profiles = input_profiles::list_for_day(day);
+
+    if profiles.is_empty() {
+        eprintln!("No inputs found for day {day}.");
+        process::exit(1);
+    }
+
+    let mut failed = false;
+
+    for (i, profile) in profiles.iter().enumerate() {
+        if !quiet {
+            if i > 0 {
+                println!();
+            }
+            println!(
+                "{ANSI_BOLD}Input: {}{ANSI_RESET}",
+                input_profiles::label(profile.as_deref())
+            );
+        }
+        failed |= !run_solution(&cmd_args, profile.as_deref());
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Returns `false` if the solution exited with a non-zero status, e.g. because submitting failed.
+fn run_solution(cmd_args: &[String], profile: Option<&str>) -> bool {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .args(cmd_args)
+        .env(input_profiles::ENV_VAR, profile.unwrap_or_default())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    cmd.wait().unwrap().success()
 }
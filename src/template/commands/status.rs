@@ -0,0 +1,261 @@
+use std::{fs, path::Path};
+
+use crate::template::{
+    commands::all::get_path_for_bin, encryption, examples, ANSI_BOLD, ANSI_DIM, ANSI_GREEN,
+    ANSI_RED, ANSI_RESET, ANSI_YELLOW,
+};
+use crate::{all_days, Day};
+
+/// Everything known about the progress of a single day.
+struct DayStatus {
+    day: Day,
+    has_bin: bool,
+    has_module: bool,
+    input_size: Option<u64>,
+    is_input_encrypted: bool,
+    example_files: usize,
+    has_manifest: bool,
+    has_puzzle: bool,
+    answers: Vec<String>,
+    benchmark: Option<(String, String)>,
+    /// parts that no example in the manifest has an expected answer for.
+    unchecked_parts: Vec<u8>,
+}
+
+impl DayStatus {
+    fn collect(day: Day, readme: &str, modules: &[String]) -> Self {
+        let bin_path = get_path_for_bin(day);
+        let bin_source = fs::read_to_string(&bin_path).ok();
+
+        let puzzle = fs::read_to_string(format!("data/puzzles/{day}.md")).ok();
+
+        Self {
+            day,
+            has_bin: bin_source.is_some(),
+            has_module: modules
+                .iter()
+                .any(|m| m.starts_with(&format!("day{}_", day.into_inner()))),
+            input_size: fs::metadata(encryption::get_input_path(day))
+                .or_else(|_| fs::metadata(encryption::get_encrypted_path(day)))
+                .ok()
+                .map(|m| m.len()),
+            is_input_encrypted: !Path::new(&encryption::get_input_path(day)).exists()
+                && Path::new(&encryption::get_encrypted_path(day)).exists(),
+            example_files: count_example_files(day),
+            has_manifest: Path::new(&examples::get_manifest_path(day)).exists(),
+            has_puzzle: puzzle.is_some(),
+            answers: puzzle.as_deref().map(parse_answers).unwrap_or_default(),
+            benchmark: parse_benchmark(readme, day),
+            unchecked_parts: examples::read_manifest(day)
+                .map(|manifest| {
+                    [1, 2]
+                        .into_iter()
+                        .filter(|&part| manifest.iter().all(|e| e.expected(part).is_none()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn stars(&self) -> usize {
+        self.answers.len()
+    }
+
+    fn is_untouched(&self) -> bool {
+        !self.has_bin && self.input_size.is_none() && !self.has_puzzle && self.stars() == 0
+    }
+
+    fn warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+
+        if self.input_size == Some(0) {
+            warnings.push("input file is empty, run `cargo download` to fetch it".into());
+        }
+        if self.has_bin && self.input_size.is_none() {
+            warnings.push("solution exists but there is no input file".into());
+        }
+        if self.has_bin && !self.has_manifest {
+            warnings.push("solution exists but there is no example manifest".into());
+        }
+        if self.has_bin {
+            for part in &self.unchecked_parts {
+                warnings.push(format!("example manifest does not check part {part}"));
+            }
+        }
+        if !self.has_bin && self.stars() > 0 {
+            warnings.push("puzzle has answers but there is no solution".into());
+        }
+
+        warnings
+    }
+}
+
+pub fn handle() {
+    let readme = fs::read_to_string("README.md").unwrap_or_default();
+    let modules: Vec<String> = fs::read_dir("src")
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let days: Vec<DayStatus> = all_days()
+        .map(|day| DayStatus::collect(day, &readme, &modules))
+        .collect();
+
+    println!(
+        "{ANSI_BOLD}{:<5}{:<5}{:<5}{:<7}{:<10}{:<10}{:<8}{:<7}{:<26}Benchmark{ANSI_RESET}",
+        "Day", "Bin", "Lib", "Input", "Examples", "Manifest", "Puzzle", "Stars", "Answers"
+    );
+
+    for status in days.iter().filter(|s| !s.is_untouched()) {
+        let examples = match status.example_files {
+            0 => mark(false),
+            n => n.to_string(),
+        };
+
+        let input = match status.input_size {
+            Some(0) => "empty".into(),
+            Some(_) if status.is_input_encrypted => "enc".into(),
+            Some(_) => mark(true),
+            None => mark(false),
+        };
+
+        let benchmark = status
+            .benchmark
+            .as_ref()
+            .map_or_else(|| "-".into(), |(p1, p2)| format!("{p1} / {p2}"));
+
+        let answers = if status.answers.is_empty() {
+            "-".into()
+        } else {
+            status.answers.join(" / ")
+        };
+
+        println!(
+            "{:<5}{:<5}{:<5}{:<7}{:<10}{:<10}{:<8}{:<7}{:<26}{}",
+            status.day.to_string(),
+            mark(status.has_bin),
+            mark(status.has_module),
+            input,
+            examples,
+            mark(status.has_manifest),
+            mark(status.has_puzzle),
+            "*".repeat(status.stars()),
+            answers,
+            benchmark
+        );
+    }
+
+    println!();
+    print_calendar(&days);
+
+    let warnings: Vec<(Day, String)> = days
+        .iter()
+        .flat_map(|s| s.warnings().into_iter().map(|w| (s.day, w)))
+        .collect();
+
+    if !warnings.is_empty() {
+        println!();
+        for (day, warning) in warnings {
+            println!("{ANSI_RED}Day {day}:{ANSI_RESET} {warning}");
+        }
+    }
+}
+
+fn mark(value: bool) -> String {
+    if value { "✔" } else { "✖" }.into()
+}
+
+/// Prints a grid of all days, gold for two stars, bold for one, green for unsubmitted solutions.
+fn print_calendar(days: &[DayStatus]) {
+    for row in days.chunks(5) {
+        let line: Vec<String> = row
+            .iter()
+            .map(|status| {
+                let stars = format!("{:<2}", "*".repeat(status.stars()));
+                let color = match (status.stars(), status.has_bin) {
+                    (2, _) => ANSI_YELLOW,
+                    (1, _) => ANSI_BOLD,
+                    (0, true) => ANSI_GREEN,
+                    _ => ANSI_DIM,
+                };
+                format!("{color}{} {stars}{ANSI_RESET}", status.day)
+            })
+            .collect();
+
+        println!("{}", line.join("  "));
+    }
+}
+
+fn count_example_files(day: Day) -> usize {
+    let prefix = day.to_string();
+
+    fs::read_dir("data/examples")
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| {
+                    name == &format!("{prefix}.txt")
+                        || (name.starts_with(&format!("{prefix}-")) && name.ends_with(".txt"))
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Extracts the answers that aoc-cli records in the puzzle description once a part is solved.
+/// The description only contains them if it was downloaded or read again after solving the part.
+fn parse_answers(puzzle: &str) -> Vec<String> {
+    puzzle
+        .match_indices("Your puzzle answer was `")
+        .filter_map(|(i, marker)| {
+            let rest = &puzzle[i + marker.len()..];
+            rest.split_once('`').map(|(answer, _)| answer.to_string())
+        })
+        .collect()
+}
+
+/// Reads the part timings of a day from the benchmark table in the readme.
+fn parse_benchmark(readme: &str, day: Day) -> Option<(String, String)> {
+    let row_prefix = format!("| [Day {}]", day.into_inner());
+    let row = readme.lines().find(|line| line.starts_with(&row_prefix))?;
+
+    let cells: Vec<&str> = row
+        .split('|')
+        .map(|cell| cell.trim().trim_matches('`'))
+        .filter(|cell| !cell.is_empty())
+        .collect();
+
+    // | Day | Parse | Part 1 | Part 2 |
+    match cells.as_slice() {
+        [_, _, part_1, part_2] => Some((part_1.to_string(), part_2.to_string())),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_answers, parse_benchmark};
+    use crate::day;
+
+    #[test]
+    fn parses_answers() {
+        let puzzle = "## --- Day 1 ---\nYour puzzle answer was `55447`.\n\n## --- Part Two ---\nYour puzzle answer was `54706`.";
+        assert_eq!(parse_answers(puzzle), ["55447", "54706"]);
+        assert!(parse_answers("## --- Day 1 ---").is_empty());
+    }
+
+    #[test]
+    fn parses_benchmark() {
+        let readme = "| [Day 1](./src/bin/01.rs) | `-` | `37.9µs` | `706.4µs` |\n| [Day 10](./src/bin/10.rs) | `5.0µs` | `1.0ms` | `2.0ms` |";
+        assert_eq!(
+            parse_benchmark(readme, day!(1)),
+            Some(("37.9µs".into(), "706.4µs".into()))
+        );
+        assert_eq!(
+            parse_benchmark(readme, day!(10)),
+            Some(("1.0ms".into(), "2.0ms".into()))
+        );
+        assert_eq!(parse_benchmark(readme, day!(2)), None);
+    }
+}
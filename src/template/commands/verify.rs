@@ -0,0 +1,157 @@
+use std::{path::Path, process};
+
+use crate::template::{
+    answers,
+    commands::{all::get_path_for_bin, run_captured},
+    input_profiles, ANSI_BOLD, ANSI_RESET, ANSI_YELLOW,
+};
+use crate::{all_days, Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Correct,
+    Wrong(String),
+    InputChanged,
+    NotRecorded,
+}
+
+pub fn handle(days: Vec<Day>, is_release: bool, all_inputs: bool) {
+    let days = if days.is_empty() {
+        all_days().collect()
+    } else {
+        days
+    };
+
+    let mut correct = 0;
+    let mut wrong = 0;
+    let mut changed = 0;
+
+    println!(
+        "{ANSI_BOLD}{:<5}{:<12}{:<36}{:<36}{ANSI_RESET}",
+        "Day", "Input", "Part 1", "Part 2"
+    );
+
+    for day in days {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            continue;
+        }
+
+        let profiles = if all_inputs {
+            input_profiles::list_for_day(day)
+        } else {
+            vec![input_profiles::current()]
+        };
+
+        let day_str = day.to_string();
+
+        for profile in profiles {
+            let profile = profile.as_deref();
+
+            // only inputs with recorded answers can be verified.
+            if !Path::new(&answers::get_profile_answers_path(day, profile)).exists() {
+                continue;
+            }
+
+            let input = input_profiles::label(profile);
+
+            let results = match run_captured(day, is_release, profile, &["--verify"]) {
+                Ok(output) => parse_results(&output),
+                Err(e) => {
+                    eprintln!("Failed to run day {day}: {e}");
+                    vec![]
+                }
+            };
+
+            if results.is_empty() {
+                println!("{day_str:<5}{input:<12}{:<36}", "no results");
+                wrong += 1;
+                continue;
+            }
+
+            let cell = |part: u8| {
+                results
+                    .iter()
+                    .find(|(p, _)| *p == part)
+                    .map_or_else(|| "-".into(), |(_, outcome)| format_cell(outcome))
+            };
+            println!("{day_str:<5}{input:<12}{:<36}{:<36}", cell(1), cell(2));
+
+            for (_, outcome) in &results {
+                match outcome {
+                    Outcome::Correct => correct += 1,
+                    Outcome::Wrong(_) => wrong += 1,
+                    Outcome::InputChanged => changed += 1,
+                    Outcome::NotRecorded => {}
+                }
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}{correct} correct, {wrong} wrong, {changed} with changed input.{ANSI_RESET}"
+    );
+
+    if changed > 0 {
+        println!("{ANSI_YELLOW}Answers recorded for a different input were not compared, run `cargo solve <day> --record` to update them.{ANSI_RESET}");
+    }
+
+    if wrong > 0 {
+        process::exit(1);
+    }
+}
+
+fn format_cell(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Correct => "✔ correct".into(),
+        Outcome::Wrong(detail) => format!("✖ {detail}"),
+        Outcome::InputChanged => "? input changed".into(),
+        Outcome::NotRecorded => "- not recorded".into(),
+    }
+}
+
+/// Parse lines like `Part 1 verification: wrong answer, expected 142`.
+fn parse_results(output: &[String]) -> Vec<(u8, Outcome)> {
+    output
+        .iter()
+        .filter_map(|line| {
+            let rest = line.strip_prefix("Part ")?;
+            let (part, result) = rest.split_once(" verification: ")?;
+
+            let outcome = match result {
+                "correct" => Outcome::Correct,
+                "input changed" => Outcome::InputChanged,
+                "not recorded" => Outcome::NotRecorded,
+                other => Outcome::Wrong(other.into()),
+            };
+
+            Some((part.parse().ok()?, outcome))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_results, Outcome};
+
+    #[test]
+    fn parses_verification_results() {
+        let results = parse_results(&[
+            "Part 1: 142 (1.2µs)".into(),
+            "Part 1 verification: correct".into(),
+            "Part 2 verification: wrong answer, expected 281".into(),
+            "Part 2 verification: input changed".into(),
+            "Part 1 verification: no answer".into(),
+        ]);
+
+        assert_eq!(
+            results,
+            [
+                (1, Outcome::Correct),
+                (2, Outcome::Wrong("wrong answer, expected 281".into())),
+                (2, Outcome::InputChanged),
+                (1, Outcome::Wrong("no answer".into())),
+            ]
+        );
+    }
+}
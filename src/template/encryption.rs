@@ -0,0 +1,254 @@
+//! Module that stores puzzle inputs encrypted at rest, so they can be committed without publishing them.
+//!
+//! An encrypted input lives next to the plain one as `data/inputs/<day>.txt.enc` (or `data/inputs/<profile>/<day>.txt.enc`
+//! for alternate input profiles) and is decrypted transparently by
+//! [`read_input`]. The key is read from `AOC_INPUT_KEY` (64 hex characters) or from the file at `AOC_INPUT_KEY_FILE`,
+//! which defaults to `~/.config/advent_of_code/input.key`. It must never be stored inside the repository.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::template::input_profiles;
+use crate::Day;
+
+/// Marks the start of every encrypted file, followed by a 12 byte nonce and the ciphertext.
+const MAGIC: &[u8] = b"AOCENC01";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingKey,
+    InvalidKey,
+    KeyExists(PathBuf),
+    NotEncrypted,
+    /// the file was encrypted with a different key or has been modified.
+    Decryption,
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingKey => write!(
+                f,
+                "no input key found, set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE, or run `cargo encrypt` to create one"
+            ),
+            Error::InvalidKey => write!(f, "input key must consist of 64 hex characters"),
+            Error::KeyExists(path) => write!(f, "key file {} already exists", path.display()),
+            Error::NotEncrypted => write!(f, "file is not an encrypted input"),
+            Error::Decryption => write!(f, "decryption failed, the key is wrong or the file is corrupted"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Path of the plain input of a day for the current input profile.
+#[must_use]
+pub fn get_input_path(day: Day) -> String {
+    let dir = input_profiles::get_inputs_dir(input_profiles::current().as_deref());
+    format!("{dir}/{day}.txt")
+}
+
+/// Path of the encrypted input of a day for the current input profile.
+#[must_use]
+pub fn get_encrypted_path(day: Day) -> String {
+    format!("{}.enc", get_input_path(day))
+}
+
+/// Reads the input of a day, preferring the plain file and falling back to the encrypted one.
+pub fn read_input(day: Day) -> Result<String, Error> {
+    let path = get_input_path(day);
+
+    if Path::new(&path).exists() {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    let data = fs::read(get_encrypted_path(day))?;
+    let plaintext = decrypt(&read_key()?, &data)?;
+    String::from_utf8(plaintext).map_err(|_| Error::Decryption)
+}
+
+#[must_use]
+pub fn get_key_path() -> Option<PathBuf> {
+    match env::var_os("AOC_INPUT_KEY_FILE") {
+        Some(path) => Some(path.into()),
+        None => env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".config/advent_of_code/input.key")),
+    }
+}
+
+pub fn read_key() -> Result<Key, Error> {
+    let hex = match env::var("AOC_INPUT_KEY") {
+        Ok(hex) => hex,
+        Err(_) => {
+            let path = get_key_path().ok_or(Error::MissingKey)?;
+            fs::read_to_string(path).map_err(|_| Error::MissingKey)?
+        }
+    };
+
+    parse_key(hex.trim())
+}
+
+/// Creates a new random key at the key path and returns that path.
+pub fn generate_key() -> Result<PathBuf, Error> {
+    let path = get_key_path().ok_or(Error::MissingKey)?;
+
+    if path.exists() {
+        return Err(Error::KeyExists(path));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+    fs::write(&path, format!("{}\n", to_hex(&key)))?;
+    Ok(path)
+}
+
+/// Replaces every plain input, including those of input profiles, with an encrypted one, returns the number of encrypted files.
+pub fn encrypt_folder(key: &Key) -> Result<usize, Error> {
+    let mut count = 0;
+
+    for path in list_inputs(".txt")? {
+        let plaintext = fs::read(&path)?;
+        let mut encrypted_path = path.clone().into_os_string();
+        encrypted_path.push(".enc");
+
+        fs::write(&encrypted_path, encrypt(key, &plaintext))?;
+        fs::remove_file(&path)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Replaces every encrypted input with a plain one, returns the number of decrypted files.
+/// Nothing is written if any of the files can not be decrypted with `key`.
+pub fn decrypt_folder(key: &Key) -> Result<usize, Error> {
+    let decrypted = list_inputs(".txt.enc")?
+        .into_iter()
+        .map(|path| {
+            let plaintext = decrypt(key, &fs::read(&path)?)?;
+            Ok((path, plaintext))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    for (path, plaintext) in &decrypted {
+        fs::write(path.with_extension(""), plaintext)?;
+        fs::remove_file(path)?;
+    }
+
+    Ok(decrypted.len())
+}
+
+fn list_inputs(suffix: &str) -> Result<Vec<PathBuf>, Error> {
+    let profiles = input_profiles::list_all();
+    let dirs = std::iter::once(input_profiles::get_inputs_dir(None)).chain(
+        profiles
+            .iter()
+            .map(|profile| input_profiles::get_inputs_dir(Some(profile))),
+    );
+
+    let mut paths = vec![];
+
+    for dir in dirs {
+        paths.extend(
+            fs::read_dir(dir)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.ends_with(suffix))
+                }),
+        );
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plaintext)
+        .expect("encryption of an in-memory buffer does not fail");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, Error> {
+    let data = data.strip_prefix(MAGIC).ok_or(Error::NotEncrypted)?;
+
+    if data.len() < NONCE_LEN {
+        return Err(Error::NotEncrypted);
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::Decryption)
+}
+
+fn parse_key(hex: &str) -> Result<Key, Error> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(Error::InvalidKey);
+    }
+
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| Error::InvalidKey))
+        .collect::<Result<Vec<u8>, Error>>()?;
+
+    Ok(*Key::from_slice(&bytes))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decrypt, encrypt, parse_key, to_hex, Error};
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn roundtrips_inputs() {
+        let key = parse_key(KEY).unwrap();
+        let encrypted = encrypt(&key, b"1abc2\npqr3stu8vwx");
+
+        assert!(!encrypted.windows(5).any(|w| w == b"1abc2"));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"1abc2\npqr3stu8vwx");
+        assert_eq!(to_hex(&key), KEY);
+    }
+
+    #[test]
+    fn rejects_wrong_key_and_plain_files() {
+        let key = parse_key(KEY).unwrap();
+        let other = parse_key(&KEY.replace("1f", "ff")).unwrap();
+        let encrypted = encrypt(&key, b"input");
+
+        assert!(matches!(
+            decrypt(&other, &encrypted),
+            Err(Error::Decryption)
+        ));
+        assert!(matches!(decrypt(&key, b"input"), Err(Error::NotEncrypted)));
+        assert!(matches!(parse_key("abc"), Err(Error::InvalidKey)));
+    }
+}
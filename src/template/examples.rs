@@ -0,0 +1,140 @@
+//! Module that reads the per-day example manifests in `data/examples`.
+//!
+//! A manifest lists one example file per line, followed by the expected answers for part 1 and part 2.
+//! A `-` marks a part that should not be checked against this example, lines starting with `#` are comments.
+//!
+//! ```text
+//! # example   part 1   part 2
+//! 08-1.txt    2        -
+//! 08-3.txt    -        6
+//! ```
+
+use std::{env, fmt::Display, fs, io};
+
+use crate::Day;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(msg) => write!(f, "{msg}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub file: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Example {
+    /// The expected answer for a part, if this example should be checked for it.
+    #[must_use]
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Reads the contents of the example file.
+    pub fn read_input(&self) -> Result<String, Error> {
+        let cwd = env::current_dir()?;
+        let filepath = cwd.join("data").join("examples").join(&self.file);
+        Ok(fs::read_to_string(filepath)?)
+    }
+}
+
+#[must_use]
+pub fn get_manifest_path(day: Day) -> String {
+    format!("data/examples/{day}.manifest")
+}
+
+pub fn read_manifest(day: Day) -> Result<Vec<Example>, Error> {
+    let manifest = fs::read_to_string(get_manifest_path(day))?;
+    parse_manifest(&manifest)
+}
+
+fn parse_answer(s: &str) -> Option<String> {
+    if s == "-" {
+        None
+    } else {
+        Some(s.into())
+    }
+}
+
+fn parse_manifest(s: &str) -> Result<Vec<Example>, Error> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            let [file, part_1, part_2] = line
+                .split_ascii_whitespace()
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| {
+                    Error::Parser(format!(
+                        "line {}: expected `<file> <part 1> <part 2>`, got \"{line}\"",
+                        i + 1
+                    ))
+                })?;
+
+            Ok(Example {
+                file: file.into(),
+                part_1: parse_answer(part_1),
+                part_2: parse_answer(part_2),
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_manifest, Example};
+
+    #[test]
+    fn parses_manifest() {
+        let manifest = "# example part1 part2\n\n08-1.txt 2 -\n08-2.txt   6   -\n08-3.txt - 6\n";
+        assert_eq!(
+            parse_manifest(manifest).unwrap(),
+            vec![
+                Example {
+                    file: "08-1.txt".into(),
+                    part_1: Some("2".into()),
+                    part_2: None,
+                },
+                Example {
+                    file: "08-2.txt".into(),
+                    part_1: Some("6".into()),
+                    part_2: None,
+                },
+                Example {
+                    file: "08-3.txt".into(),
+                    part_1: None,
+                    part_2: Some("6".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_on_missing_columns() {
+        assert!(parse_manifest("08-1.txt 2").is_err());
+        assert!(parse_manifest("08-1.txt 2 3 4").is_err());
+    }
+}
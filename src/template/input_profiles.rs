@@ -0,0 +1,74 @@
+//! Module for alternate input sets, e.g. the inputs of teammates' accounts.
+//!
+//! The default input of a day lives in `data/inputs/<day>.txt`, the input of a profile in
+//! `data/inputs/<profile>/<day>.txt`. Solution bins read the input of the profile named in `AOC_INPUT_PROFILE`.
+
+use std::{env, fs, path::Path};
+
+use crate::Day;
+
+pub const ENV_VAR: &str = "AOC_INPUT_PROFILE";
+const INPUTS_DIR: &str = "data/inputs";
+
+/// The profile selected for the current process, [`None`] for the default input.
+#[must_use]
+pub fn current() -> Option<String> {
+    env::var(ENV_VAR).ok().filter(|profile| !profile.is_empty())
+}
+
+#[must_use]
+pub fn get_inputs_dir(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("{INPUTS_DIR}/{profile}"),
+        None => INPUTS_DIR.into(),
+    }
+}
+
+/// Display name of a profile.
+#[must_use]
+pub fn label(profile: Option<&str>) -> &str {
+    profile.unwrap_or("default")
+}
+
+/// Lists all profile directories, sorted by name.
+#[must_use]
+pub fn list_all() -> Vec<String> {
+    let mut profiles: Vec<String> = fs::read_dir(INPUTS_DIR)
+        .map(|dir| {
+            dir.filter_map(|entry| {
+                let entry = entry.ok()?;
+                entry.file_type().ok()?.is_dir().then_some(())?;
+                entry.file_name().into_string().ok()
+            })
+            .collect()
+        })
+        .unwrap_or_default();
+
+    profiles.sort();
+    profiles
+}
+
+/// Lists the inputs that exist for a day, starting with the default input.
+#[must_use]
+pub fn list_for_day(day: Day) -> Vec<Option<String>> {
+    let has_input = |profile: Option<&str>| {
+        let dir = get_inputs_dir(profile);
+        Path::new(&format!("{dir}/{day}.txt")).exists()
+            || Path::new(&format!("{dir}/{day}.txt.enc")).exists()
+    };
+
+    let mut profiles = vec![];
+
+    if has_input(None) {
+        profiles.push(None);
+    }
+
+    profiles.extend(
+        list_all()
+            .into_iter()
+            .filter(|profile| has_input(Some(profile)))
+            .map(Some),
+    );
+
+    profiles
+}
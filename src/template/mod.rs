@@ -1,18 +1,40 @@
 use crate::Day;
 use std::{env, fs};
+use terminal::Style;
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod benchmark_history;
 pub mod commands;
+pub mod encryption;
+pub mod examples;
+pub mod input_profiles;
+pub mod ocr;
+pub mod profiler;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod scaling;
+pub mod stats;
+pub mod terminal;
 
-pub const ANSI_ITALIC: &str = "\x1b[3m";
-pub const ANSI_BOLD: &str = "\x1b[1m";
-pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_ITALIC: Style = Style::new("\x1b[3m");
+pub const ANSI_BOLD: Style = Style::new("\x1b[1m");
+pub const ANSI_RESET: Style = Style::new("\x1b[0m");
+pub const ANSI_DIM: Style = Style::new("\x1b[2m");
+pub const ANSI_RED: Style = Style::new("\x1b[31m");
+pub const ANSI_GREEN: Style = Style::new("\x1b[32m");
+pub const ANSI_YELLOW: Style = Style::new("\x1b[33m");
+pub const ANSI_CYAN: Style = Style::new("\x1b[36m");
 
 /// Helper function that reads a text file to a string.
+/// Inputs that are only stored encrypted (`<day>.txt.enc`) are decrypted transparently.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
+    if folder == "inputs" {
+        return encryption::read_input(day)
+            .unwrap_or_else(|e| panic!("could not open input file: {e}"));
+    }
+
     let cwd = env::current_dir().unwrap();
     let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
@@ -31,18 +53,108 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     f.expect("could not open input file")
 }
 
+/// Helper function that computes a checksum of an input, used to tell apart answers and benchmarks of different inputs.
+/// This is a 64-bit FNV-1a hash, it is stable across platforms and Rust versions but not cryptographically secure.
+#[must_use]
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+///
+/// With `solution!(DAY, parse = fn)`, the input is parsed once by `fn` and a reference to the parsed value is
+/// passed to both parts. Parsing is timed separately from the parts.
+///
+/// With `solution!(DAY, parse = fn, visualise = fn)`, `cargo solve DAY --visualise` prints what the second `fn`
+/// returns for the parsed input before running the parts.
+///
+/// With `solution!(DAY, stack = "256MB")` (also combinable with `parse`), the solution runs on a thread with a
+/// larger stack, e.g. for deeply recursive searches. The `AOC_STACK_SIZE` environment variable overrides it.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@plain $day:expr, $stack:expr) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            run_with_stack($stack, || {
+                if std::env::args().any(|x| x == "--examples") {
+                    run_examples(part_one, part_two, DAY);
+                    return;
+                }
+                if std::env::args().any(|x| x == "--scale") {
+                    run_scale(part_one, part_two, DAY);
+                    return;
+                }
+                let input = advent_of_code::template::read_file("inputs", DAY);
+                if is_part_selected(1) {
+                    run_part(part_one, &input, DAY, 1);
+                }
+                if is_part_selected(2) {
+                    run_part(part_two, &input, DAY, 2);
+                }
+            });
         }
     };
+    (@parsed $day:expr, $parse:expr, $stack:expr $(, $visualise:expr)?) => {
+        /// The current day.
+        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            run_with_stack($stack, || {
+                if std::env::args().any(|x| x == "--examples") {
+                    run_examples(
+                        |input: &str| part_one(&($parse)(input)),
+                        |input: &str| part_two(&($parse)(input)),
+                        DAY,
+                    );
+                    return;
+                }
+                if std::env::args().any(|x| x == "--scale") {
+                    run_scale(
+                        |input: &str| part_one(&($parse)(input)),
+                        |input: &str| part_two(&($parse)(input)),
+                        DAY,
+                    );
+                    return;
+                }
+                let input = advent_of_code::template::read_file("inputs", DAY);
+                let parsed = run_parse($parse, &input);
+                $(
+                    if is_visualising() {
+                        println!("{}", ($visualise)(&parsed));
+                    }
+                )?
+                if is_part_selected(1) {
+                    run_part(part_one, &parsed, DAY, 1);
+                }
+                if is_part_selected(2) {
+                    run_part(part_two, &parsed, DAY, 2);
+                }
+            });
+        }
+    };
+    ($day:expr) => {
+        advent_of_code::solution!(@plain $day, None);
+    };
+    ($day:expr, stack = $stack:expr) => {
+        advent_of_code::solution!(@plain $day, Some($stack));
+    };
+    ($day:expr, parse = $parse:expr) => {
+        advent_of_code::solution!(@parsed $day, $parse, None);
+    };
+    ($day:expr, parse = $parse:expr, stack = $stack:expr) => {
+        advent_of_code::solution!(@parsed $day, $parse, Some($stack));
+    };
+    ($day:expr, parse = $parse:expr, visualise = $visualise:expr) => {
+        advent_of_code::solution!(@parsed $day, $parse, None, $visualise);
+    };
+    ($day:expr, parse = $parse:expr, visualise = $visualise:expr, stack = $stack:expr) => {
+        advent_of_code::solution!(@parsed $day, $parse, Some($stack), $visualise);
+    };
 }
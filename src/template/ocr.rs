@@ -0,0 +1,291 @@
+//! Module for recognising capital letters in answers that are rendered as ASCII art.
+
+/// A block font with its glyphs drawn as rows of `#` and `.`.
+struct Font {
+    width: usize,
+    height: usize,
+    /// distance between the left edges of two adjacent letters.
+    stride: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const FONT_4X6: Font = Font {
+    width: 4,
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+const FONT_6X10: Font = Font {
+    width: 6,
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Decodes ASCII art into the letters it shows.
+/// Supports the 4x6 and 6x10 block fonts used by Advent of Code puzzles, with lit pixels drawn as `#` or `█`.
+/// Returns [`None`] if the input is not made of known glyphs in one of the supported fonts.
+///
+/// ```text
+/// .##..###.
+/// #..#.#..#
+/// #..#.###.   decodes to "AB"
+/// ####.#..#
+/// #..#.#..#
+/// #..#.###.
+/// ```
+#[must_use]
+pub fn decode(art: &str) -> Option<String> {
+    let pixels = parse_pixels(art)?;
+    let font = [FONT_4X6, FONT_6X10]
+        .into_iter()
+        .find(|font| font.height == pixels.len())?;
+
+    let width = pixels.iter().map(Vec::len).max()?;
+    let is_lit = |x: usize, y: usize| pixels[y].get(x).copied().unwrap_or(false);
+    let is_blank_column = |x: usize| (0..font.height).all(|y| !is_lit(x, y));
+
+    // glyphs like `I` or `J` start with blank columns, so the first letter may begin left of the first lit pixel.
+    let first_lit = (0..width).find(|&x| !is_blank_column(x))?;
+
+    (first_lit.saturating_sub(font.width - 1)..=first_lit).find_map(|start| {
+        let mut letters = String::new();
+        let mut x = start;
+
+        while x < width && !(x..width).all(is_blank_column) {
+            let (letter, _) = font.glyphs.iter().find(|(_, rows)| {
+                rows.iter().enumerate().all(|(y, row)| {
+                    row.chars()
+                        .enumerate()
+                        .all(|(dx, c)| (c == '#') == is_lit(x + dx, y))
+                }) && (x + font.width..x + font.stride).all(is_blank_column)
+            })?;
+
+            letters.push(*letter);
+            x += font.stride;
+        }
+
+        Some(letters)
+    })
+}
+
+/// Converts the art into rows of lit pixels, dropping blank rows above and below the letters.
+fn parse_pixels(art: &str) -> Option<Vec<Vec<bool>>> {
+    let rows = art
+        .lines()
+        .map(|line| {
+            line.trim_end()
+                .chars()
+                .map(|c| match c {
+                    '#' | '█' => Some(true),
+                    '.' | ' ' => Some(false),
+                    _ => None,
+                })
+                .collect::<Option<Vec<bool>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let first = rows.iter().position(|row| row.contains(&true))?;
+    let last = rows.iter().rposition(|row| row.contains(&true))?;
+
+    Some(rows[first..=last].to_vec())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::decode;
+
+    #[test]
+    fn decodes_small_font() {
+        let art = [
+            ".##..###...##..####",
+            "#..#.#..#.#..#.#...",
+            "#..#.###..#....###.",
+            "####.#..#.#....#...",
+            "#..#.#..#.#..#.#...",
+            "#..#.###...##..####",
+        ]
+        .join("\n");
+        assert_eq!(decode(&art), Some("ABCE".into()));
+    }
+
+    #[test]
+    fn decodes_block_characters_and_margins() {
+        let art = [
+            "",
+            "  █  █ ████   ",
+            "  █  █    █   ",
+            "  ████   █    ",
+            "  █  █  █     ",
+            "  █  █ █      ",
+            "  █  █ ████   ",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(decode(&art), Some("HZ".into()));
+    }
+
+    #[test]
+    fn decodes_leading_blank_glyph_columns() {
+        let art = [
+            ".###.#..#",
+            "..#..#..#",
+            "..#..####",
+            "..#..#..#",
+            "..#..#..#",
+            ".###.#..#",
+        ]
+        .join("\n");
+        assert_eq!(decode(&art), Some("IH".into()));
+    }
+
+    #[test]
+    fn decodes_large_font() {
+        let art = [
+            "#....#..######",
+            "#....#.......#",
+            ".#..#........#",
+            ".#..#.......#.",
+            "..##.......#..",
+            "..##......#...",
+            ".#..#....#....",
+            ".#..#...#.....",
+            "#....#..#.....",
+            "#....#..######",
+        ]
+        .join("\n");
+        assert_eq!(decode(&art), Some("XZ".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(decode("12\n34"), None);
+        assert_eq!(decode("#..#\n#..#"), None);
+        assert_eq!(decode("####\n####\n####\n####\n####\n####"), None);
+    }
+}
@@ -0,0 +1,251 @@
+//! A small phase profiler for solutions and library code.
+//!
+//! Spans are created with [`span`] and close when the returned guard is dropped. Nested spans form a tree,
+//! repeated spans with the same name under the same parent are merged and counted.
+//! Profiling is only enabled by the runner for `solve --profile`, otherwise a span costs a single atomic load.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::template::{ANSI_ITALIC, ANSI_RESET};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static PROFILE: Mutex<Profile> = Mutex::new(Profile::new());
+
+struct Node {
+    name: &'static str,
+    total: Duration,
+    calls: u64,
+    children: Vec<usize>,
+}
+
+struct Profile {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+    /// indices of the currently open spans, innermost last.
+    stack: Vec<usize>,
+}
+
+impl Profile {
+    const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+        self.stack.clear();
+    }
+
+    /// Opens a span below the innermost open span and returns the index of its node.
+    fn enter(&mut self, name: &'static str) -> usize {
+        let parent = self.stack.last().copied();
+
+        let siblings = match parent {
+            Some(parent) => &self.nodes[parent].children,
+            None => &self.roots,
+        };
+
+        let existing = siblings
+            .iter()
+            .copied()
+            .find(|&i| self.nodes[i].name == name);
+
+        let index = existing.unwrap_or_else(|| {
+            self.nodes.push(Node {
+                name,
+                total: Duration::ZERO,
+                calls: 0,
+                children: vec![],
+            });
+            let index = self.nodes.len() - 1;
+            match parent {
+                Some(parent) => self.nodes[parent].children.push(index),
+                None => self.roots.push(index),
+            }
+            index
+        });
+
+        self.stack.push(index);
+        index
+    }
+
+    /// Closes the span of node `index`, which does not have to be the innermost one.
+    fn exit(&mut self, index: usize, elapsed: Duration) {
+        if let Some(position) = self.stack.iter().rposition(|&open| open == index) {
+            self.stack.remove(position);
+        }
+
+        if let Some(node) = self.nodes.get_mut(index) {
+            node.total += elapsed;
+            node.calls += 1;
+        }
+    }
+
+    fn lines(&self) -> Vec<(String, Duration, u64)> {
+        let mut lines = vec![];
+        for (i, &root) in self.roots.iter().enumerate() {
+            self.push_lines(root, "", i == self.roots.len() - 1, &mut lines);
+        }
+        lines
+    }
+
+    fn push_lines(
+        &self,
+        index: usize,
+        prefix: &str,
+        is_last: bool,
+        lines: &mut Vec<(String, Duration, u64)>,
+    ) {
+        let node = &self.nodes[index];
+        let branch = if is_last { "└─ " } else { "├─ " };
+        lines.push((
+            format!("{prefix}{branch}{}", node.name),
+            node.total,
+            node.calls,
+        ));
+
+        let child_prefix = format!("{prefix}{}", if is_last { "   " } else { "│  " });
+        for (i, &child) in node.children.iter().enumerate() {
+            self.push_lines(child, &child_prefix, i == node.children.len() - 1, lines);
+        }
+    }
+}
+
+/// Guard for an open span, records the elapsed time when dropped.
+#[must_use = "a span closes as soon as it is dropped"]
+pub struct Span {
+    /// the node of this span and when it was opened, [`None`] if profiling is disabled.
+    start: Option<(usize, Instant)>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some((index, start)) = self.start {
+            let elapsed = start.elapsed();
+            if let Ok(mut profile) = PROFILE.lock() {
+                profile.exit(index, elapsed);
+            }
+        }
+    }
+}
+
+/// Opens a named span that lasts until the returned guard is dropped.
+///
+/// ```
+/// # use advent_of_code::template::runner;
+/// let _span = runner::span("expand");
+/// ```
+#[inline]
+pub fn span(name: &'static str) -> Span {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return Span { start: None };
+    }
+
+    let Ok(mut profile) = PROFILE.lock() else {
+        return Span { start: None };
+    };
+    let index = profile.enter(name);
+    drop(profile);
+
+    Span {
+        start: Some((index, Instant::now())),
+    }
+}
+
+pub(crate) fn enable() {
+    if let Ok(mut profile) = PROFILE.lock() {
+        profile.clear();
+    }
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub(crate) fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Prints the spans recorded since the profiler was last enabled as a tree and clears them.
+pub(crate) fn print_report() {
+    let Ok(mut profile) = PROFILE.lock() else {
+        return;
+    };
+
+    let lines = profile.lines();
+    profile.clear();
+
+    if lines.is_empty() {
+        println!("{ANSI_ITALIC}no spans recorded{ANSI_RESET}");
+        return;
+    }
+
+    let width = lines
+        .iter()
+        .map(|(name, _, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (name, total, calls) in lines {
+        let padding = " ".repeat(width - name.chars().count());
+        println!("{name}{padding}  {total:>10.1?}  ×{calls}");
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Profile;
+    use std::time::Duration;
+
+    #[test]
+    fn merges_repeated_spans() {
+        let mut profile = Profile::new();
+
+        let solve = profile.enter("solve");
+        for _ in 0..3 {
+            let step = profile.enter("step");
+            profile.exit(step, Duration::from_micros(2));
+        }
+        let finish = profile.enter("finish");
+        profile.exit(finish, Duration::from_micros(1));
+        profile.exit(solve, Duration::from_micros(10));
+        let other = profile.enter("other");
+        profile.exit(other, Duration::from_micros(5));
+
+        let lines = profile.lines();
+        let names: Vec<&str> = lines.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["├─ solve", "│  ├─ step", "│  └─ finish", "└─ other"]
+        );
+        assert_eq!(lines[1].1, Duration::from_micros(6));
+        assert_eq!(lines[1].2, 3);
+        assert_eq!(lines[0].2, 1);
+    }
+
+    #[test]
+    fn closes_spans_out_of_order() {
+        let mut profile = Profile::new();
+
+        let outer = profile.enter("outer");
+        let inner = profile.enter("inner");
+        profile.exit(outer, Duration::from_micros(10));
+        profile.exit(inner, Duration::from_micros(3));
+        let next = profile.enter("next");
+        profile.exit(next, Duration::from_micros(1));
+
+        let lines = profile.lines();
+        let names: Vec<&str> = lines.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, ["├─ outer", "│  └─ inner", "└─ next"]);
+        assert_eq!(lines[0].1, Duration::from_micros(10));
+        assert_eq!(lines[1].1, Duration::from_micros(3));
+    }
+}
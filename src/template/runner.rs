@@ -1,14 +1,95 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
+use crate::generate::{self, Rng};
+use crate::template::examples::{self, Example};
+use crate::template::{
+    answers, aoc_cli, checksum, encryption, ocr, profiler, read_file, scaling,
+    terminal::{self, Style},
+    ANSI_GREEN, ANSI_ITALIC, ANSI_RESET, ANSI_YELLOW,
+};
 use crate::Day;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
+pub use profiler::{span, Span};
+
+/// Runs `main` on a thread with the stack size given by `AOC_STACK_SIZE` or `stack`, e.g. `"256MB"`.
+/// Without either, `main` runs on the current thread. Panics of `main` are resumed on the current thread.
+pub fn run_with_stack(stack: Option<&str>, main: impl FnOnce() + Send) {
+    let Some(stack) = env::var("AOC_STACK_SIZE").ok().or(stack.map(String::from)) else {
+        main();
+        return;
+    };
+
+    let Some(stack_size) = parse_size(&stack) else {
+        eprintln!("Invalid stack size \"{stack}\", expected a size like \"256MB\".");
+        process::exit(1);
+    };
+
+    thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .name("solution".into())
+            .stack_size(stack_size)
+            .spawn_scoped(scope, main)
+            .expect("failed to spawn solution thread");
+
+        if let Err(payload) = handle.join() {
+            panic::resume_unwind(payload);
+        }
+    });
+}
+
+/// Parses sizes like `8388608`, `512KB` or `256 MB`. Units are binary, i.e. `1KB` is 1024 bytes.
+fn parse_size(s: &str) -> Option<usize> {
+    let s = s.trim().to_ascii_uppercase();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+
+    let multiplier = match unit.trim().trim_end_matches("IB").trim_end_matches('B') {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => return None,
+    };
+
+    number.parse::<usize>().ok()?.checked_mul(multiplier)
+}
+
+/// Returns `true` if `part` should run, i.e. if no `--part` was passed or it matches `part`.
+/// Prints a `skipped` line for parts that are not selected, an empty line in quiet mode.
+pub fn is_part_selected(part: u8) -> bool {
+    let selected = selected_part().is_none_or(|selected| selected == part);
+
+    if !selected {
+        if terminal::is_quiet() {
+            // like a part without an answer, so the answer of part two stays on the second line.
+            println!();
+        } else {
+            println!("Part {part}: skipped");
+        }
+    }
+
+    selected
+}
+
+fn selected_part() -> Option<u8> {
+    let args: Vec<String> = env::args().collect();
+    let part_index = args.iter().position(|x| x == "--part")? + 1;
+
+    match args.get(part_index).and_then(|x| x.parse::<u8>().ok()) {
+        Some(part) => Some(part),
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --part 2");
+            process::exit(1);
+        }
+    }
+}
+
 pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
@@ -17,8 +98,223 @@ pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, d
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
+    if is_profiling() {
+        profiler::print_report();
+    }
+
+    let answer = result.as_ref().and_then(format_answer);
+
+    if terminal::is_quiet() {
+        // a part without an answer prints an empty line, which keeps the answer of part two on the second line.
+        println!("{}", answer.as_deref().unwrap_or_default());
+    }
+
+    if env::args().any(|x| x == "--record") {
+        record_result(answer.as_deref(), day, part);
+    }
+
+    if env::args().any(|x| x == "--verify") {
+        verify_result(answer.as_deref(), day, part);
+    }
+
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if let Some(Err(e)) = submit_result(result, day, part) {
+            eprintln!("Failed to submit: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Run the input parser of a solution and print its execution time as a separate `Parse` line.
+pub fn run_parse<T>(func: impl Fn(&str) -> T, input: &str) -> T {
+    let is_interactive = !terminal::is_plain() && !terminal::is_quiet();
+
+    if is_interactive {
+        print!("Parse:");
+        let _ = stdout().flush();
+    }
+
+    let (result, duration, samples) = run_timed(func, input, |_| {});
+
+    if is_interactive {
+        print!("\r");
+    }
+
+    if !terminal::is_quiet() {
+        println!("Parse:{}", format_duration(&duration, samples));
+    }
+
+    if is_profiling() {
+        profiler::print_report();
+    }
+
+    result
+}
+
+/// Run both parts of a solution against every example listed in the manifest for `day`.
+/// Prints one line per checked example and part, e.g. `08-1.txt Part 1: pass (1.2µs)`.
+pub fn run_examples<T1: Display, T2: Display>(
+    part_one: impl Fn(&str) -> Option<T1>,
+    part_two: impl Fn(&str) -> Option<T2>,
+    day: Day,
+) {
+    let examples = match examples::read_manifest(day) {
+        Ok(examples) => examples,
+        Err(e) => {
+            eprintln!("Failed to read example manifest: {e}");
+            process::exit(1);
+        }
+    };
+
+    for example in &examples {
+        let input = match example.read_input() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Failed to read example \"{}\": {e}", example.file);
+                process::exit(1);
+            }
+        };
+
+        check_example(&part_one, &input, example, 1);
+        check_example(&part_two, &input, example, 2);
+    }
+}
+
+fn check_example<T: Display>(
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    example: &Example,
+    part: u8,
+) {
+    let Some(expected) = example.expected(part) else {
+        return;
+    };
+
+    if selected_part().is_some_and(|selected| selected != part) {
+        return;
+    }
+
+    let timer = Instant::now();
+    // a panicking example should not prevent the remaining examples from running.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+    let duration = timer.elapsed();
+
+    let outcome = match result {
+        Ok(Some(result)) if result.to_string() == expected => "pass".into(),
+        Ok(Some(result)) => format!(
+            "fail, got {}, expected {expected}",
+            result.to_string().replace('\n', "\\n")
+        ),
+        Ok(None) => format!("fail, got ✖, expected {expected}"),
+        Err(_) => "panic".into(),
+    };
+
+    println!("{} Part {part}: {outcome} ({duration:.1?})", example.file);
+}
+
+/// Run both parts of a solution on inputs of growing size, then chart the timings and estimate the complexity of
+/// each part. Inputs are generated for days with an input generator, otherwise (or with `--real`) the lines of the
+/// real input are truncated or repeated.
+pub fn run_scale<T1, T2>(
+    part_one: impl Fn(&str) -> Option<T1>,
+    part_two: impl Fn(&str) -> Option<T2>,
+    day: Day,
+) {
+    let inputs = match scale_inputs(day) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to read input: {e}");
+            process::exit(1);
+        }
+    };
+
+    if selected_part().is_none_or(|part| part == 1) {
+        scale_part(part_one, &inputs, 1, ANSI_GREEN);
+    }
+
+    if selected_part().is_none_or(|part| part == 2) {
+        scale_part(part_two, &inputs, 2, ANSI_YELLOW);
+    }
+}
+
+fn scale_inputs(day: Day) -> Result<Vec<(usize, String)>, encryption::Error> {
+    let use_real_input = env::args().any(|x| x == "--real");
+
+    if !use_real_input && generate::for_day(day, 0, &mut Rng::new(0)).is_some() {
+        println!(
+            "{ANSI_ITALIC}Scaling on generated inputs, n is the number of entries.{ANSI_RESET}"
+        );
+        return Ok([125, 250, 500, 1000, 2000, 4000, 8000]
+            .into_iter()
+            .filter_map(|n| Some((n, generate::for_day(day, n, &mut Rng::new(1))?)))
+            .collect());
+    }
+
+    println!("{ANSI_ITALIC}Scaling on the real input, n is the number of lines.{ANSI_RESET}");
+    let input = encryption::read_input(day)?;
+    let lines = input.lines().count();
+
+    let mut sizes: Vec<usize> = [(1, 8), (1, 4), (1, 2), (1, 1), (2, 1), (4, 1), (8, 1)]
+        .into_iter()
+        .map(|(numerator, denominator)| lines * numerator / denominator)
+        .filter(|&n| n > 0)
+        .collect();
+    sizes.dedup();
+
+    Ok(sizes
+        .into_iter()
+        .map(|n| (n, scaling::resize_input(&input, n)))
+        .collect())
+}
+
+fn scale_part<T>(
+    func: impl Fn(&str) -> Option<T>,
+    inputs: &[(usize, String)],
+    part: u8,
+    color: Style,
+) {
+    let mut measurements = vec![];
+
+    println!();
+    println!("{ANSI_BOLD}Part {part}{ANSI_RESET}");
+
+    for (n, input) in inputs {
+        let timer = Instant::now();
+        // puzzles may not support arbitrary sizes, e.g. because of overflows.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+        let base_time = timer.elapsed();
+
+        if result.is_err() {
+            println!("{ANSI_ITALIC}panicked at n = {n}, skipping larger inputs{ANSI_RESET}");
+            break;
+        }
+
+        let (duration, _) = measure(
+            |input: &str| func(input),
+            input.as_str(),
+            &base_time,
+            Duration::from_millis(200),
+        );
+        measurements.push((*n, duration));
+
+        if base_time > Duration::from_secs(1) {
+            println!(
+                "{ANSI_ITALIC}n = {n} took longer than 1s, skipping larger inputs{ANSI_RESET}"
+            );
+            break;
+        }
+    }
+
+    for row in scaling::chart(&measurements, color, 40) {
+        println!("{row}");
+    }
+
+    match scaling::fit(&measurements) {
+        Some(fit) => println!(
+            "Part {part}: {ANSI_BOLD}{}{ANSI_RESET} (power-law exponent {:.2})",
+            fit.complexity, fit.exponent
+        ),
+        None => println!("Part {part}: not enough measurements to estimate the complexity"),
     }
 }
 
@@ -30,10 +326,17 @@ fn run_timed<I: Clone, T>(
     input: I,
     hook: impl Fn(&T),
 ) -> (T, Duration, u128) {
+    // only the first execution is profiled, benchmark iterations run with spans disabled.
+    if is_profiling() {
+        profiler::enable();
+    }
+
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
+    profiler::disable();
+
     hook(&result);
 
     let run = if std::env::args().any(|x| x == "--time") {
@@ -45,19 +348,34 @@ fn run_timed<I: Clone, T>(
     (result, run.0, run.1)
 }
 
+fn is_profiling() -> bool {
+    env::args().any(|x| x == "--profile")
+}
+
+/// Returns `true` if the parsed input should be drawn before running the parts, see [`crate::solution`].
+pub fn is_visualising() -> bool {
+    env::args().any(|x| x == "--visualise")
+}
+
 fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+    if !terminal::is_plain() && !terminal::is_quiet() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    measure(func, input, base_time, Duration::from_secs(1))
+}
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+/// Runs `func` repeatedly for roughly `budget`, but at least 10 times.
+/// Returns the average duration and the number of samples.
+fn measure<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    budget: Duration,
+) -> (Duration, u128) {
+    let bench_iterations =
+        (budget.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -92,8 +410,17 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
+/// Prints the result of a part. On an interactive terminal, an intermediate result is shown while benchmarking and
+/// overwritten with the final result, plain output only contains the final result.
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
+    let is_plain = terminal::is_plain();
+
+    if terminal::is_quiet() || (is_plain && is_intermediate_result) {
+        return;
+    }
+
+    let carriage_return = if is_plain { "" } else { "\r" };
 
     match result {
         Some(result) => {
@@ -102,16 +429,20 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
-                    print!("\r");
+                    print!("{carriage_return}");
                     println!("{str}");
                     println!("{result}");
+
+                    if let Some(letters) = ocr::decode(&result.to_string()) {
+                        println!("Decoded: {ANSI_BOLD}{letters}{ANSI_RESET}");
+                    }
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
-                    print!("\r");
+                    print!("{carriage_return}");
                     println!("{str}");
                 }
             }
@@ -119,6 +450,8 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
         None => {
             if is_intermediate_result {
                 print!("{part}: ✖");
+            } else if is_plain {
+                println!("{part}: ✖");
             } else {
                 print!("\r");
                 println!("{part}: ✖             ");
@@ -129,12 +462,12 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. aoc-cli is installed, otherwise the answer is queued.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<(), aoc_cli::AocCommandError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -157,11 +490,90 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let Some(answer) = format_answer(&result) else {
+        eprintln!("Could not decode multi-line result into letters, submit it manually.");
         process::exit(1);
-    }
+    };
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(aoc_cli::submit(day, part, &answer))
+}
+
+/// The answer as it is submitted: answers rendered as ASCII art are decoded into the letters they show.
+fn format_answer<T: Display>(result: &T) -> Option<String> {
+    let answer = result.to_string();
+
+    if answer.contains('\n') {
+        ocr::decode(&answer)
+    } else {
+        Some(answer)
+    }
+}
+
+fn input_checksum(day: Day) -> String {
+    checksum(&read_file("inputs", day))
+}
+
+/// Stores the answer of a part together with the checksum of the current input.
+fn record_result(answer: Option<&str>, day: Day, part: u8) {
+    let Some(answer) = answer else {
+        eprintln!("Part {part} has no answer to record.");
+        return;
+    };
+
+    match answers::record(day, part, answer, &input_checksum(day)) {
+        Ok(()) => println!("Recorded answer for part {part}."),
+        Err(e) => eprintln!("Failed to record answer for part {part}: {e}"),
+    }
+}
+
+/// Compares the answer of a part to the recorded answer.
+/// Prints a line like `Part 1 verification: correct` that is read by the `verify` command.
+fn verify_result(answer: Option<&str>, day: Day, part: u8) {
+    match answer {
+        Some(answer) => {
+            let verification = answers::verify(day, part, answer, &input_checksum(day));
+            println!("Part {part} verification: {verification}");
+        }
+        None => println!("Part {part} verification: no answer"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{hint::black_box, panic};
+
+    use super::{parse_size, run_with_stack};
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("8388608"), Some(8_388_608));
+        assert_eq!(parse_size("512KB"), Some(512 * 1024));
+        assert_eq!(parse_size("256 MB"), Some(256 * 1024 * 1024));
+        assert_eq!(parse_size("1gib"), Some(1024 * 1024 * 1024));
+        assert_eq!(parse_size("MB"), None);
+        assert_eq!(parse_size("12 apples"), None);
+    }
+
+    fn recurse(depth: u32) -> u32 {
+        let padding = black_box([0u8; 512]);
+        if depth == 0 {
+            return u32::from(padding[0]);
+        }
+        recurse(depth - 1) + u32::from(padding[depth as usize % 512]) + 1
+    }
+
+    #[test]
+    fn runs_on_larger_stack() {
+        // ~50MB of stack frames, far more than the default of a test thread.
+        let mut result = 0;
+        run_with_stack(Some("256MB"), || result = recurse(100_000));
+        assert_eq!(result, 100_000);
+    }
+
+    #[test]
+    fn propagates_panics() {
+        let result = panic::catch_unwind(|| run_with_stack(Some("1MB"), || panic!("expected")));
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,194 @@
+//! Module that estimates how the run time of a solution grows with the size of its input.
+//!
+//! Measurements are fitted to `t = c * f(n)` for a set of complexity classes in log space, the class with the smallest
+//! residual wins. A free power law `t = c * n^k` is fitted as well, its exponent hints at classes between the models.
+
+use std::time::Duration;
+
+use crate::template::{stats::mean, terminal::Style, ANSI_DIM, ANSI_RESET};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Complexity {
+    Constant,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+impl Complexity {
+    const ALL: [Complexity; 5] = [
+        Complexity::Constant,
+        Complexity::Linear,
+        Complexity::Linearithmic,
+        Complexity::Quadratic,
+        Complexity::Cubic,
+    ];
+
+    fn ln_model(self, n: f64) -> f64 {
+        match self {
+            Complexity::Constant => 0.0,
+            Complexity::Linear => n.ln(),
+            // log n is clamped to 1, so the model stays defined for tiny inputs.
+            Complexity::Linearithmic => n.ln() + n.log2().max(1.0).ln(),
+            Complexity::Quadratic => 2.0 * n.ln(),
+            Complexity::Cubic => 3.0 * n.ln(),
+        }
+    }
+}
+
+impl std::fmt::Display for Complexity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Complexity::Constant => "O(1)",
+            Complexity::Linear => "O(n)",
+            Complexity::Linearithmic => "O(n log n)",
+            Complexity::Quadratic => "O(n²)",
+            Complexity::Cubic => "O(n³)",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub complexity: Complexity,
+    /// exponent `k` of the best fitting power law `t = c * n^k`.
+    pub exponent: f64,
+}
+
+/// Fits measurements of `(input size, duration)` pairs. Returns [`None`] for fewer than three measurements.
+#[must_use]
+pub fn fit(measurements: &[(usize, Duration)]) -> Option<Fit> {
+    if measurements.len() < 3 {
+        return None;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let points: Vec<(f64, f64)> = measurements
+        .iter()
+        .map(|(n, t)| (*n as f64, (t.as_nanos() as f64).max(1.0).ln()))
+        .collect();
+
+    let residual = |complexity: Complexity| {
+        let offsets: Vec<f64> = points
+            .iter()
+            .map(|(n, ln_t)| ln_t - complexity.ln_model(*n))
+            .collect();
+        let ln_c = mean(&offsets);
+        offsets.iter().map(|o| (o - ln_c).powi(2)).sum::<f64>()
+    };
+
+    let complexity = Complexity::ALL
+        .into_iter()
+        .min_by(|a, b| residual(*a).total_cmp(&residual(*b)))?;
+
+    let ln_n: Vec<f64> = points.iter().map(|(n, _)| n.ln()).collect();
+    let ln_t: Vec<f64> = points.iter().map(|(_, ln_t)| *ln_t).collect();
+    let (mean_n, mean_t) = (mean(&ln_n), mean(&ln_t));
+    let covariance: f64 = ln_n
+        .iter()
+        .zip(&ln_t)
+        .map(|(n, t)| (n - mean_n) * (t - mean_t))
+        .sum();
+    let variance: f64 = ln_n.iter().map(|n| (n - mean_n).powi(2)).sum();
+
+    Some(Fit {
+        complexity,
+        exponent: covariance / variance,
+    })
+}
+
+/// Builds an input with `lines` lines by truncating `input` or repeating its lines.
+#[must_use]
+pub fn resize_input(input: &str, lines: usize) -> String {
+    input
+        .lines()
+        .cycle()
+        .take(lines)
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+/// Renders measurements as a bar chart with one row per input size.
+#[must_use]
+pub fn chart(measurements: &[(usize, Duration)], color: Style, width: usize) -> Vec<String> {
+    const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+    let max = measurements
+        .iter()
+        .map(|(_, t)| t.as_nanos())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let label_width = measurements
+        .iter()
+        .map(|(n, _)| n.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    measurements
+        .iter()
+        .map(|(n, t)| {
+            #[allow(clippy::cast_possible_truncation)]
+            let eighths = (t.as_nanos() * width as u128 * 8 / max) as usize;
+            let bar = format!("{}{}", "█".repeat(eighths / 8), EIGHTHS[eighths % 8]);
+            format!("{n:>label_width$} {ANSI_DIM}│{ANSI_RESET}{color}{bar}{ANSI_RESET} {t:.1?}")
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{chart, fit, resize_input, Complexity};
+    use crate::template::ANSI_GREEN;
+
+    fn measurements(f: impl Fn(f64) -> f64) -> Vec<(usize, Duration)> {
+        [100, 200, 400, 800, 1600, 3200]
+            .into_iter()
+            .map(|n| (n, Duration::from_nanos(f(n as f64) as u64)))
+            .collect()
+    }
+
+    #[test]
+    fn fits_complexity_classes() {
+        let linear = fit(&measurements(|n| 50.0 * n + 20.0)).unwrap();
+        assert_eq!(linear.complexity, Complexity::Linear);
+        assert!((linear.exponent - 1.0).abs() < 0.05);
+
+        let quadratic = fit(&measurements(|n| 3.0 * n * n)).unwrap();
+        assert_eq!(quadratic.complexity, Complexity::Quadratic);
+
+        let linearithmic = fit(&measurements(|n| 10.0 * n * n.log2())).unwrap();
+        assert_eq!(linearithmic.complexity, Complexity::Linearithmic);
+
+        let constant = fit(&measurements(|_| 1000.0)).unwrap();
+        assert_eq!(constant.complexity, Complexity::Constant);
+
+        assert_eq!(fit(&measurements(|n| n)[..2]), None);
+    }
+
+    #[test]
+    fn resizes_inputs() {
+        assert_eq!(resize_input("a\nb\nc\n", 2), "a\nb\n");
+        assert_eq!(resize_input("a\nb", 5), "a\nb\na\nb\na\n");
+    }
+
+    #[test]
+    fn draws_proportional_bars() {
+        let rows = chart(
+            &[
+                (1, Duration::from_nanos(10)),
+                (20, Duration::from_nanos(20)),
+            ],
+            ANSI_GREEN,
+            4,
+        );
+        assert!(rows[0].starts_with(" 1 "));
+        assert_eq!(rows[0].matches('█').count(), 2);
+        assert_eq!(rows[1].matches('█').count(), 4);
+    }
+}
@@ -0,0 +1,134 @@
+//! Module for statistics helpers that compare benchmark samples.
+
+/// Arithmetic mean of the samples.
+#[must_use]
+pub fn mean(values: &[f64]) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let len = values.len() as f64;
+    values.iter().sum::<f64>() / len
+}
+
+/// Sample variance (with Bessel's correction).
+#[must_use]
+pub fn variance(values: &[f64]) -> f64 {
+    let mean = mean(values);
+    #[allow(clippy::cast_precision_loss)]
+    let len = values.len() as f64;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (len - 1.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WelchTest {
+    pub t: f64,
+    /// degrees of freedom after Welch–Satterthwaite.
+    pub df: f64,
+}
+
+impl WelchTest {
+    /// Returns `true` if the means differ at a two-sided significance level of 5%.
+    #[must_use]
+    pub fn is_significant(&self) -> bool {
+        self.t.abs() > critical_value(self.df)
+    }
+}
+
+/// Welch's t-test for two samples with possibly different variances.
+/// Returns [`None`] if one of the samples has fewer than two values.
+#[must_use]
+pub fn welch_t_test(a: &[f64], b: &[f64]) -> Option<WelchTest> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let (len_a, len_b) = (a.len() as f64, b.len() as f64);
+    let (se_a, se_b) = (variance(a) / len_a, variance(b) / len_b);
+    let difference = mean(a) - mean(b);
+
+    if se_a + se_b == 0.0 {
+        // samples without noise either differ or they don't.
+        let t = if difference == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+        return Some(WelchTest {
+            t,
+            df: len_a + len_b - 2.0,
+        });
+    }
+
+    let df = (se_a + se_b).powi(2) / (se_a.powi(2) / (len_a - 1.0) + se_b.powi(2) / (len_b - 1.0));
+
+    Some(WelchTest {
+        t: difference / (se_a + se_b).sqrt(),
+        df,
+    })
+}
+
+/// Two-sided critical values of Student's t-distribution at 5%, rounding the degrees of freedom down.
+fn critical_value(df: f64) -> f64 {
+    if df > 120.0 {
+        return 1.960;
+    }
+
+    const TABLE: [(f64, f64); 15] = [
+        (1.0, 12.706),
+        (2.0, 4.303),
+        (3.0, 3.182),
+        (4.0, 2.776),
+        (5.0, 2.571),
+        (6.0, 2.447),
+        (7.0, 2.365),
+        (8.0, 2.306),
+        (9.0, 2.262),
+        (10.0, 2.228),
+        (12.0, 2.179),
+        (15.0, 2.131),
+        (20.0, 2.086),
+        (30.0, 2.042),
+        (60.0, 2.000),
+    ];
+
+    TABLE
+        .iter()
+        .rev()
+        .find(|(table_df, _)| *table_df <= df)
+        .map_or(f64::INFINITY, |(_, value)| *value)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{critical_value, mean, variance, welch_t_test};
+
+    #[test]
+    fn computes_moments() {
+        assert_eq!(mean(&[1.0, 2.0, 3.0, 6.0]), 3.0);
+        assert_eq!(variance(&[1.0, 2.0, 3.0, 6.0]), 14.0 / 3.0);
+    }
+
+    #[test]
+    fn detects_significant_differences() {
+        let fast = [100.0, 102.0, 98.0, 101.0, 99.0];
+        let slow = [120.0, 118.0, 123.0, 119.0, 121.0];
+        let noisy = [90.0, 115.0, 95.0, 110.0, 100.0];
+
+        assert!(welch_t_test(&fast, &slow).unwrap().is_significant());
+        assert!(!welch_t_test(&fast, &noisy).unwrap().is_significant());
+        assert!(welch_t_test(&fast, &[100.0]).is_none());
+        assert!(welch_t_test(&[1.0, 1.0], &[2.0, 2.0])
+            .unwrap()
+            .is_significant());
+        assert!(!welch_t_test(&[1.0, 1.0], &[1.0, 1.0])
+            .unwrap()
+            .is_significant());
+    }
+
+    #[test]
+    fn looks_up_critical_values() {
+        assert_eq!(critical_value(4.7), 2.776);
+        assert_eq!(critical_value(45.0), 2.042);
+        assert_eq!(critical_value(500.0), 1.960);
+        assert_eq!(critical_value(0.5), f64::INFINITY);
+    }
+}
@@ -0,0 +1,91 @@
+//! Module that decides how output is styled.
+//!
+//! Colors and lines that update in place only work on an interactive terminal, so output is plain if stdout is
+//! not a terminal, `NO_COLOR` is set or `--plain` is passed. Solution bins usually write into a pipe of the main
+//! binary, which therefore passes its own decision on through `AOC_PLAIN`.
+
+use std::{
+    env,
+    fmt::{self, Display},
+    io::{stdout, IsTerminal},
+    sync::OnceLock,
+};
+
+pub const ENV_VAR: &str = "AOC_PLAIN";
+
+/// An ANSI escape code that is only written if the output is not plain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style(&'static str);
+
+impl Style {
+    #[must_use]
+    pub const fn new(code: &'static str) -> Self {
+        Self(code)
+    }
+
+    /// The escape code, regardless of whether the output is plain.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if is_plain() {
+            Ok(())
+        } else {
+            f.write_str(self.0)
+        }
+    }
+}
+
+/// Returns `true` if output must not contain escape codes or carriage returns.
+pub fn is_plain() -> bool {
+    static PLAIN: OnceLock<bool> = OnceLock::new();
+    *PLAIN.get_or_init(|| {
+        detect(
+            env::args().any(|x| x == "--plain"),
+            env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()),
+            env::var(ENV_VAR).ok().as_deref(),
+            stdout().is_terminal(),
+        )
+    })
+}
+
+/// Returns `true` if only answers should be printed, one per line.
+pub fn is_quiet() -> bool {
+    env::args().any(|x| x == "--quiet")
+}
+
+fn detect(plain_flag: bool, no_color: bool, inherited: Option<&str>, is_terminal: bool) -> bool {
+    if plain_flag || no_color {
+        return true;
+    }
+
+    match inherited {
+        Some("1") => true,
+        Some("0") => false,
+        _ => !is_terminal,
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::detect;
+
+    #[test]
+    fn detects_plain_output() {
+        assert!(!detect(false, false, None, true));
+        assert!(detect(false, false, None, false));
+        assert!(detect(true, false, None, true));
+        assert!(detect(false, true, Some("0"), true));
+    }
+
+    #[test]
+    fn prefers_decision_of_parent() {
+        assert!(!detect(false, false, Some("0"), false));
+        assert!(detect(false, false, Some("1"), true));
+        assert!(!detect(false, false, Some(""), true));
+    }
+}